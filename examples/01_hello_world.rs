@@ -1,4 +1,4 @@
-use meos::{meos_initialize, MeosEnum, TGeomPoint};
+use meos::{meos_initialize, MeosEnum, MfJsonOptions, TGeomPoint};
 
 fn main() {
     meos_initialize();
@@ -33,9 +33,13 @@ fn main() {
     };
 
     // Convert results to MF-JSON
+    let mfjson_options = MfJsonOptions::new()
+        .with_bbox(true)
+        .precision(6)
+        .srs("4326")
+        .pretty();
 
-    let instant_mfjson =
-        TGeomPoint::Instant(inst).as_mfjson(true, meos::JSONCVariant::Pretty, 6, "4326");
+    let instant_mfjson = TGeomPoint::Instant(inst).as_mfjson(&mfjson_options);
     println!(
         "\n\
             --------------------\n\
@@ -48,8 +52,7 @@ fn main() {
         instant_wkt, instant_mfjson
     );
 
-    let seq_disc_mfjson =
-        TGeomPoint::Sequence(seq_disc).as_mfjson(true, meos::JSONCVariant::Pretty, 6, "4326");
+    let seq_disc_mfjson = TGeomPoint::Sequence(seq_disc).as_mfjson(&mfjson_options);
     println!(
         "\n\
             -------------------------------------------------\n\
@@ -62,8 +65,7 @@ fn main() {
         sequence_discrete_wkt, seq_disc_mfjson
     );
 
-    let seq_linear_mfjson =
-        TGeomPoint::Sequence(seq_linear).as_mfjson(true, meos::JSONCVariant::Pretty, 6, "4326");
+    let seq_linear_mfjson = TGeomPoint::Sequence(seq_linear).as_mfjson(&mfjson_options);
     println!(
         "\n\
             -----------------------------------------------\n\
@@ -76,8 +78,7 @@ fn main() {
         sequence_linear_wkt, seq_linear_mfjson
     );
 
-    let seq_step_mfjson =
-        TGeomPoint::Sequence(seq_step).as_mfjson(true, meos::JSONCVariant::Pretty, 6, "4326");
+    let seq_step_mfjson = TGeomPoint::Sequence(seq_step).as_mfjson(&mfjson_options);
     println!(
         "\n\
             --------------------------------------------\n\
@@ -90,8 +91,7 @@ fn main() {
         sequence_step_wkt, seq_step_mfjson
     );
 
-    let ss_linear_mfjson =
-        TGeomPoint::SequenceSet(ss_linear).as_mfjson(true, meos::JSONCVariant::Pretty, 6, "4326");
+    let ss_linear_mfjson = TGeomPoint::SequenceSet(ss_linear).as_mfjson(&mfjson_options);
     println!(
         "\n\
             ---------------------------------------------------\n\
@@ -104,8 +104,7 @@ fn main() {
         sequence_set_linear_wkt, ss_linear_mfjson
     );
 
-    let ss_step_mfjson =
-        TGeomPoint::SequenceSet(ss_step).as_mfjson(true, meos::JSONCVariant::Pretty, 6, "4326");
+    let ss_step_mfjson = TGeomPoint::SequenceSet(ss_step).as_mfjson(&mfjson_options);
     println!(
         "\n\
             ------------------------------------------------\n\
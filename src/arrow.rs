@@ -0,0 +1,449 @@
+//! Conversions between temporal values and Apache Arrow arrays.
+//!
+//! Two layouts are supported:
+//!
+//! * A binary column holding the (extended, little endian) WKB of each
+//!   temporal value, which round-trips exactly.
+//! * A GeoArrow-style nested layout, `list<struct<x, y, z, t>>` for temporal
+//!   points and `list<struct<value, t>>` for temporal numbers, with one list
+//!   entry per instant. This layout only keeps the instants, values read back
+//!   are sequences using the default interpolation of their type (linear for
+//!   `TGeomPoint` and `TFloat`, step for `TInt`). Reading an entry whose
+//!   instants are empty or not strictly ordered by time is an error.
+
+use std::sync::Arc;
+
+use arrow_array::{
+    cast::AsArray,
+    types::{Float64Type, Int32Type, TimestampMicrosecondType},
+    Array, ArrayRef, BinaryArray, Float64Array, Int32Array, ListArray, StructArray,
+    TimestampMicrosecondArray,
+};
+use arrow_buffer::OffsetBuffer;
+use arrow_schema::{ArrowError, DataType, Field, Fields, TimeUnit};
+use chrono::{DateTime, Utc};
+
+use crate::{
+    MeosEnum, Point, TFloat, TFloatInstant, TGeomPoint, TGeomPointInstant, TGeomPointSequence,
+    TInstant, TInt, TIntInstant, TInterpolation, TPointTrait, TSequence, TSequenceBuilder,
+    Temporal, WKBVariant,
+};
+
+const TIMEZONE: &str = "UTC";
+
+fn time_field() -> Field {
+    Field::new(
+        "t",
+        DataType::Timestamp(TimeUnit::Microsecond, Some(TIMEZONE.into())),
+        false,
+    )
+}
+
+fn point_fields() -> Fields {
+    Fields::from(vec![
+        Field::new("x", DataType::Float64, false),
+        Field::new("y", DataType::Float64, false),
+        Field::new("z", DataType::Float64, true),
+        time_field(),
+    ])
+}
+
+fn value_fields(value_type: DataType) -> Fields {
+    Fields::from(vec![Field::new("value", value_type, false), time_field()])
+}
+
+fn timestamps_array(timestamps: Vec<i64>) -> ArrayRef {
+    Arc::new(TimestampMicrosecondArray::from(timestamps).with_timezone(TIMEZONE))
+}
+
+fn list_array(fields: Fields, lengths: Vec<usize>, columns: Vec<ArrayRef>) -> ListArray {
+    let values = StructArray::new(fields.clone(), columns, None);
+    ListArray::new(
+        Arc::new(Field::new_struct("item", fields, false)),
+        OffsetBuffer::from_lengths(lengths),
+        Arc::new(values),
+        None,
+    )
+}
+
+fn column<'a>(entry: &'a StructArray, name: &str) -> Result<&'a ArrayRef, ArrowError> {
+    entry
+        .column_by_name(name)
+        .ok_or_else(|| ArrowError::SchemaError(format!("Missing field `{name}`")))
+}
+
+/// Returns the entries of `array` as struct arrays, failing on null or empty entries.
+fn list_entries(array: &ListArray) -> Result<Vec<StructArray>, ArrowError> {
+    (0..array.len())
+        .map(|i| {
+            if array.is_null(i) || array.value_length(i) == 0 {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Null or empty temporal value at index {i}"
+                )));
+            }
+            array
+                .value(i)
+                .as_struct_opt()
+                .cloned()
+                .ok_or_else(|| ArrowError::CastError("Expected a list of structs".to_owned()))
+        })
+        .collect()
+}
+
+fn timestamps(entry: &StructArray) -> Result<Vec<DateTime<Utc>>, ArrowError> {
+    let times = column(entry, "t")?
+        .as_primitive_opt::<TimestampMicrosecondType>()
+        .ok_or_else(|| {
+            ArrowError::CastError("Field `t` must be a microsecond timestamp".to_owned())
+        })?;
+    times
+        .values()
+        .iter()
+        .map(|&t| {
+            DateTime::from_timestamp_micros(t)
+                .ok_or_else(|| ArrowError::ComputeError(format!("Invalid timestamp {t}")))
+        })
+        .collect()
+}
+
+/// Reads the temporal values of a binary array of WKB values, failing on null
+/// or invalid entries.
+fn from_wkb_array<T: MeosEnum>(array: &BinaryArray) -> Result<Vec<T>, ArrowError> {
+    array
+        .iter()
+        .enumerate()
+        .map(|(i, wkb)| {
+            let wkb = wkb.ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!("Null temporal value at index {i}"))
+            })?;
+            T::try_from_wkb(wkb)
+                .map_err(|_| ArrowError::ParseError(format!("Invalid temporal WKB at index {i}")))
+        })
+        .collect()
+}
+
+/// Builds the sequence of the list entry at `index` from its instants.
+fn sequence_from_instants<S: TSequence>(
+    index: usize,
+    instants: Vec<S::TI>,
+    interpolation: TInterpolation,
+) -> Result<S, ArrowError>
+where
+    S::TI: AsRef<S::TI>,
+{
+    let invalid = |error| {
+        ArrowError::InvalidArgumentError(format!("Invalid sequence at index {index}: {error}"))
+    };
+    let mut builder = TSequenceBuilder::new(interpolation);
+    for instant in instants {
+        builder.push(instant).map_err(invalid)?;
+    }
+    builder.build().map_err(invalid)
+}
+
+/// Converts temporal points into a binary array of WKB values.
+///
+/// ## MEOS Functions
+///
+/// `temporal_as_wkb`
+pub fn tgeompoints_to_wkb(values: &[TGeomPoint]) -> BinaryArray {
+    BinaryArray::from_iter_values(
+        values
+            .iter()
+            .map(|value| value.as_wkb(WKBVariant::NDR | WKBVariant::Extended)),
+    )
+}
+
+/// Reads temporal points from a binary array of WKB values.
+///
+/// ## MEOS Functions
+///
+/// `temporal_from_wkb`
+pub fn tgeompoints_from_wkb(array: &BinaryArray) -> Result<Vec<TGeomPoint>, ArrowError> {
+    from_wkb_array(array)
+}
+
+/// Converts temporal points into a `list<struct<x, y, z, t>>` array, with one
+/// list entry per instant. `z` is null for 2D points.
+pub fn tgeompoints_to_list(values: &[TGeomPoint]) -> ListArray {
+    let (mut xs, mut ys, mut zs, mut ts) = (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+    let lengths = values
+        .iter()
+        .map(|value| {
+            let instants = value.instants();
+            for instant in &instants {
+                let Point(x, y, z) = instant.value();
+                xs.push(x);
+                ys.push(y);
+                zs.push(z);
+                ts.push(instant.timestamp().timestamp_micros());
+            }
+            instants.len()
+        })
+        .collect();
+
+    list_array(
+        point_fields(),
+        lengths,
+        vec![
+            Arc::new(Float64Array::from(xs)),
+            Arc::new(Float64Array::from(ys)),
+            Arc::new(Float64Array::from(zs)),
+            timestamps_array(ts),
+        ],
+    )
+}
+
+/// Reads temporal points from a `list<struct<x, y, z, t>>` array, as linear
+/// sequences with the given SRID.
+pub fn tgeompoints_from_list(array: &ListArray, srid: i32) -> Result<Vec<TGeomPoint>, ArrowError> {
+    list_entries(array)?
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let coordinate = |name| {
+                column(entry, name)?
+                    .as_primitive_opt::<Float64Type>()
+                    .ok_or_else(|| ArrowError::CastError(format!("Field `{name}` must be a float")))
+            };
+            let (xs, ys) = (coordinate("x")?, coordinate("y")?);
+            let zs = entry
+                .column_by_name("z")
+                .map(|_| coordinate("z"))
+                .transpose()?;
+            let instants: Vec<_> = timestamps(entry)?
+                .into_iter()
+                .enumerate()
+                .map(|(i, t)| {
+                    let z = zs.filter(|zs| zs.is_valid(i)).map(|zs| zs.value(i));
+                    TGeomPointInstant::from_value_and_timestamp(
+                        Point(xs.value(i), ys.value(i), z),
+                        t,
+                    )
+                })
+                .collect();
+            let sequence: TGeomPointSequence =
+                sequence_from_instants(index, instants, TInterpolation::Linear)?;
+            Ok(TGeomPoint::Sequence(sequence).with_srid(srid))
+        })
+        .collect()
+}
+
+/// Converts temporal floats into a binary array of WKB values.
+///
+/// ## MEOS Functions
+///
+/// `temporal_as_wkb`
+pub fn tfloats_to_wkb(values: &[TFloat]) -> BinaryArray {
+    BinaryArray::from_iter_values(values.iter().map(|value| value.as_wkb(WKBVariant::NDR)))
+}
+
+/// Reads temporal floats from a binary array of WKB values.
+///
+/// ## MEOS Functions
+///
+/// `temporal_from_wkb`
+pub fn tfloats_from_wkb(array: &BinaryArray) -> Result<Vec<TFloat>, ArrowError> {
+    from_wkb_array(array)
+}
+
+/// Converts temporal floats into a `list<struct<value, t>>` array, with one
+/// list entry per instant.
+pub fn tfloats_to_list(values: &[TFloat]) -> ListArray {
+    let (mut vs, mut ts) = (Vec::new(), Vec::new());
+    let lengths = values
+        .iter()
+        .map(|value| {
+            let instants = value.instants();
+            for instant in &instants {
+                vs.push(instant.value());
+                ts.push(instant.timestamp().timestamp_micros());
+            }
+            instants.len()
+        })
+        .collect();
+
+    list_array(
+        value_fields(DataType::Float64),
+        lengths,
+        vec![Arc::new(Float64Array::from(vs)), timestamps_array(ts)],
+    )
+}
+
+/// Reads temporal floats from a `list<struct<value, t>>` array, as linear sequences.
+pub fn tfloats_from_list(array: &ListArray) -> Result<Vec<TFloat>, ArrowError> {
+    list_entries(array)?
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let values = column(entry, "value")?
+                .as_primitive_opt::<Float64Type>()
+                .ok_or_else(|| ArrowError::CastError("Field `value` must be a float".to_owned()))?;
+            let instants: Vec<_> = timestamps(entry)?
+                .into_iter()
+                .zip(values.values().iter())
+                .map(|(t, &v)| TFloatInstant::from_value_and_timestamp(v, t))
+                .collect();
+            Ok(TFloat::Sequence(sequence_from_instants(
+                index,
+                instants,
+                TInterpolation::Linear,
+            )?))
+        })
+        .collect()
+}
+
+/// Converts temporal integers into a binary array of WKB values.
+///
+/// ## MEOS Functions
+///
+/// `temporal_as_wkb`
+pub fn tints_to_wkb(values: &[TInt]) -> BinaryArray {
+    BinaryArray::from_iter_values(values.iter().map(|value| value.as_wkb(WKBVariant::NDR)))
+}
+
+/// Reads temporal integers from a binary array of WKB values.
+///
+/// ## MEOS Functions
+///
+/// `temporal_from_wkb`
+pub fn tints_from_wkb(array: &BinaryArray) -> Result<Vec<TInt>, ArrowError> {
+    from_wkb_array(array)
+}
+
+/// Converts temporal integers into a `list<struct<value, t>>` array, with one
+/// list entry per instant.
+pub fn tints_to_list(values: &[TInt]) -> ListArray {
+    let (mut vs, mut ts) = (Vec::new(), Vec::new());
+    let lengths = values
+        .iter()
+        .map(|value| {
+            let instants = value.instants();
+            for instant in &instants {
+                vs.push(instant.value());
+                ts.push(instant.timestamp().timestamp_micros());
+            }
+            instants.len()
+        })
+        .collect();
+
+    list_array(
+        value_fields(DataType::Int32),
+        lengths,
+        vec![Arc::new(Int32Array::from(vs)), timestamps_array(ts)],
+    )
+}
+
+/// Reads temporal integers from a `list<struct<value, t>>` array, as step sequences.
+pub fn tints_from_list(array: &ListArray) -> Result<Vec<TInt>, ArrowError> {
+    list_entries(array)?
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let values = column(entry, "value")?
+                .as_primitive_opt::<Int32Type>()
+                .ok_or_else(|| {
+                    ArrowError::CastError("Field `value` must be a 32 bit integer".to_owned())
+                })?;
+            let instants: Vec<_> = timestamps(entry)?
+                .into_iter()
+                .zip(values.values().iter())
+                .map(|(t, &v)| TIntInstant::from_value_and_timestamp(v, t))
+                .collect();
+            Ok(TInt::Sequence(sequence_from_instants(
+                index,
+                instants,
+                TInterpolation::Stepwise,
+            )?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[serial_test::serial]
+mod tests {
+    use super::*;
+    use crate::{meos_initialize, meos_initialize_timezone};
+
+    #[test]
+    fn tgeompoint_round_trip() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let tpoint: TGeomPoint =
+            "[POINT(1 1)@2020-01-01 00:00:00+00, POINT(2 3)@2020-01-01 00:01:00+00]"
+                .parse()
+                .unwrap();
+        let values = [tpoint];
+
+        let wkb = tgeompoints_to_wkb(&values);
+        assert_eq!(tgeompoints_from_wkb(&wkb).unwrap(), values);
+
+        let list = tgeompoints_to_list(&values);
+        assert_eq!(list.value_length(0), 2);
+        assert_eq!(tgeompoints_from_list(&list, 0).unwrap(), values);
+    }
+
+    #[test]
+    fn tnumber_round_trip() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let tfloat: TFloat = "[1.5@2020-01-01 00:00:00+00, 2.5@2020-01-01 00:01:00+00]"
+            .parse()
+            .unwrap();
+        let floats = [tfloat];
+        assert_eq!(tfloats_from_wkb(&tfloats_to_wkb(&floats)).unwrap(), floats);
+        assert_eq!(
+            tfloats_from_list(&tfloats_to_list(&floats)).unwrap(),
+            floats
+        );
+
+        let tint: TInt = "[1@2020-01-01 00:00:00+00, 2@2020-01-01 00:01:00+00]"
+            .parse()
+            .unwrap();
+        let ints = [tint];
+        assert_eq!(tints_from_wkb(&tints_to_wkb(&ints)).unwrap(), ints);
+        assert_eq!(tints_from_list(&tints_to_list(&ints)).unwrap(), ints);
+    }
+
+    #[test]
+    fn invalid_wkb() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let tint: TInt = "[1@2020-01-01 00:00:00+00]".parse().unwrap();
+        let valid = tint.as_wkb(WKBVariant::NDR);
+        let array = BinaryArray::from_iter_values([valid, &[1, 2, 3][..]]);
+        assert!(matches!(
+            tints_from_wkb(&array),
+            Err(ArrowError::ParseError(message)) if message.ends_with("index 1")
+        ));
+        let array = BinaryArray::from_opt_vec(vec![Some(valid), None]);
+        assert!(tints_from_wkb(&array).is_err());
+    }
+
+    #[test]
+    fn invalid_list() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let list = |timestamps: Vec<i64>| {
+            let values = vec![1.0; timestamps.len()];
+            list_array(
+                value_fields(DataType::Float64),
+                vec![values.len()],
+                vec![
+                    Arc::new(Float64Array::from(values)),
+                    timestamps_array(timestamps),
+                ],
+            )
+        };
+        assert!(matches!(
+            tfloats_from_list(&list(vec![60_000_000, 0])),
+            Err(ArrowError::InvalidArgumentError(message)) if message.contains("index 0")
+        ));
+        assert!(tfloats_from_list(&list(vec![0, 0])).is_err());
+        assert!(tfloats_from_list(&list(Vec::new())).is_err());
+        assert_eq!(
+            tfloats_from_list(&list(vec![0, 60_000_000])).unwrap().len(),
+            1
+        );
+    }
+}
@@ -17,6 +17,7 @@ use crate::{
         datetime::TsTzSpan,
     },
     errors::ParseError,
+    try_from_inner,
     utils::{create_interval, from_meos_timestamp, to_meos_timestamp},
     WKBVariant,
 };
@@ -29,10 +30,12 @@ pub struct STBox {
 
 impl MeosBox for STBox {
     fn from_wkb(wkb: &[u8]) -> Self {
+        crate::meos_initialize();
         unsafe { Self::from_inner(meos_sys::stbox_from_wkb(wkb.as_ptr(), wkb.len())) }
     }
 
     fn from_hexwkb(hexwkb: &[u8]) -> Self {
+        crate::meos_initialize();
         let c_hexwkb = CString::new(hexwkb).unwrap();
         unsafe {
             let inner = meos_sys::stbox_from_hexwkb(c_hexwkb.as_ptr());
@@ -62,6 +65,7 @@ impl MeosBox for STBox {
     /// assert_eq!(stbox.tmin().unwrap(), datetime);
     /// ```
     fn from_time<Tz: TimeZone>(time: DateTime<Tz>) -> Self {
+        crate::meos_initialize();
         // Convert DateTime<Utc> to the expected timestamp format for MEOS
         let timestamptz = to_meos_timestamp(&time);
         unsafe { Self::from_inner(meos_sys::timestamptz_to_stbox(timestamptz)) }
@@ -327,6 +331,8 @@ impl Clone for STBox {
     }
 }
 
+crate::impl_send_sync!(STBox);
+
 impl std::str::FromStr for STBox {
     type Err = ParseError;
     /// Parses a `STBox` from a string representation.
@@ -355,10 +361,11 @@ impl std::str::FromStr for STBox {
     /// assert_eq!(temporal_span, TsTzSpan::from_str("[2001-01-01, 2001-01-02]").unwrap());
     /// ```
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        CString::new(string).map_err(|_| ParseError).map(|string| {
-            let inner = unsafe { meos_sys::stbox_in(string.as_ptr()) };
-            Self::from_inner(inner)
-        })
+        let string = CString::new(string).map_err(|_| ParseError)?;
+        try_from_inner(
+            || unsafe { meos_sys::stbox_in(string.as_ptr()) },
+            Self::from_inner,
+        )
     }
 
     // ------------------------- Position Operations ---------------------------
@@ -18,6 +18,7 @@ use crate::{
     },
     errors::ParseError,
     temporal::TFloat,
+    try_from_inner,
     utils::{create_interval, from_meos_timestamp, to_meos_timestamp},
     WKBVariant,
 };
@@ -30,10 +31,12 @@ pub struct TBox {
 
 impl MeosBox for TBox {
     fn from_wkb(wkb: &[u8]) -> Self {
+        crate::meos_initialize();
         unsafe { Self::from_inner(meos_sys::tbox_from_wkb(wkb.as_ptr(), wkb.len())) }
     }
 
     fn from_hexwkb(hexwkb: &[u8]) -> Self {
+        crate::meos_initialize();
         let c_hexwkb = CString::new(hexwkb).unwrap();
         unsafe {
             let inner = meos_sys::tbox_from_hexwkb(c_hexwkb.as_ptr());
@@ -64,6 +67,7 @@ impl MeosBox for TBox {
     /// assert_eq!(tbox.tmin().unwrap(), datetime);
     /// ```
     fn from_time<Tz: TimeZone>(time: DateTime<Tz>) -> Self {
+        crate::meos_initialize();
         // Convert DateTime<Utc> to the expected timestamp format for MEOS
         let timestamptz = to_meos_timestamp(&time);
         unsafe { Self::from_inner(meos_sys::timestamptz_to_tbox(timestamptz)) }
@@ -427,7 +431,7 @@ impl MeosBox for TBox {
 }
 
 impl TBox {
-    fn inner(&self) -> *const meos_sys::TBox {
+    pub(crate) fn inner(&self) -> *const meos_sys::TBox {
         self._inner.as_ptr()
     }
 
@@ -456,6 +460,7 @@ impl TBox {
     /// assert_eq!(tbox.xmin().unwrap(), 42.0);
     /// ```
     pub fn from_int(value: i32) -> Self {
+        crate::meos_initialize();
         unsafe { Self::from_inner(meos_sys::int_to_tbox(value)) }
     }
 
@@ -476,6 +481,7 @@ impl TBox {
     /// assert_eq!(tbox.xmin().unwrap(), 42.0);
     /// ```
     pub fn from_float(value: f64) -> Self {
+        crate::meos_initialize();
         unsafe { Self::from_inner(meos_sys::float_to_tbox(value)) }
     }
 
@@ -704,6 +710,8 @@ impl Clone for TBox {
     }
 }
 
+crate::impl_send_sync!(TBox);
+
 impl std::str::FromStr for TBox {
     type Err = ParseError;
     /// Parses a `TBox` from a string representation.
@@ -735,9 +743,10 @@ impl std::str::FromStr for TBox {
     /// assert_eq!(temporal_span, TsTzSpan::from_str("[2020-06-01, 2020-06-05]").unwrap());
     /// ```
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        CString::new(string).map_err(|_| ParseError).map(|string| {
-            let inner = unsafe { meos_sys::tbox_in(string.as_ptr()) };
-            Self::from_inner(inner)
-        })
+        let string = CString::new(string).map_err(|_| ParseError)?;
+        try_from_inner(
+            || unsafe { meos_sys::tbox_in(string.as_ptr()) },
+            Self::from_inner,
+        )
     }
 }
@@ -17,6 +17,7 @@ pub trait Span: Collection {
     /// ## Returns
     /// * A new `Span` instance.
     fn from_wkb(wkb: &[u8]) -> Self {
+        crate::meos_initialize();
         let span = unsafe { meos_sys::span_from_wkb(wkb.as_ptr(), wkb.len()) };
         Self::from_inner(span)
     }
@@ -29,6 +30,7 @@ pub trait Span: Collection {
     /// ## Returns
     /// * A new `Span` instance.
     fn from_hexwkb(hexwkb: &[u8]) -> Self {
+        crate::meos_initialize();
         let c_string = CString::new(hexwkb).expect("Cannot create CString");
         let span = unsafe { meos_sys::span_from_hexwkb(c_string.as_ptr()) };
         Self::from_inner(span)
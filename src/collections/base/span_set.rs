@@ -3,7 +3,7 @@ use std::{
     ptr,
 };
 
-use crate::WKBVariant;
+use crate::{utils::from_meos_array, WKBVariant};
 
 use super::{collection::Collection, span::Span};
 
@@ -21,6 +21,7 @@ pub trait SpanSet: Collection + FromIterator<Self::SpanType> {
     /// ## Returns
     /// * A new `Span` instance.
     fn from_wkb(wkb: &[u8]) -> Self {
+        crate::meos_initialize();
         let span = unsafe { meos_sys::spanset_from_wkb(wkb.as_ptr(), wkb.len()) };
         Self::from_inner(span)
     }
@@ -33,6 +34,7 @@ pub trait SpanSet: Collection + FromIterator<Self::SpanType> {
     /// ## Returns
     /// * A new `Span` instance.
     fn from_hexwkb(hexwkb: &[u8]) -> Self {
+        crate::meos_initialize();
         let c_string = CString::new(hexwkb).expect("Cannot create CString");
         let span = unsafe { meos_sys::spanset_from_hexwkb(c_string.as_ptr()) };
         Self::from_inner(span)
@@ -93,6 +95,43 @@ pub trait SpanSet: Collection + FromIterator<Self::SpanType> {
         }
     }
 
+    /// Returns up to `span_count` spans covering the span set, each grouping
+    /// a similar number of its spans.
+    ///
+    /// ## Arguments
+    /// * `span_count` - Maximum number of spans.
+    #[doc(alias = "spanset_split_n_spans")]
+    fn split_n_spans(&self, span_count: i32) -> Vec<Self::SpanType> {
+        let mut count = 0;
+        unsafe {
+            let spans =
+                meos_sys::spanset_split_n_spans(self.inner(), span_count, ptr::addr_of_mut!(count));
+            from_meos_array(spans, count, |span| {
+                Span::from_inner(meos_sys::span_copy(span))
+            })
+        }
+    }
+
+    /// Returns the spans covering the span set, each grouping `elems_per_span`
+    /// consecutive spans of it.
+    ///
+    /// ## Arguments
+    /// * `elems_per_span` - Number of spans of the span set per resulting span.
+    #[doc(alias = "spanset_split_each_n_spans")]
+    fn split_each_n_spans(&self, elems_per_span: i32) -> Vec<Self::SpanType> {
+        let mut count = 0;
+        unsafe {
+            let spans = meos_sys::spanset_split_each_n_spans(
+                self.inner(),
+                elems_per_span,
+                ptr::addr_of_mut!(count),
+            );
+            from_meos_array(spans, count, |span| {
+                Span::from_inner(meos_sys::span_copy(span))
+            })
+        }
+    }
+
     fn width(&self, ignore_gaps: bool) -> Self::Type;
 
     /// Return a new `SpanSet` with the lower and upper bounds shifted by `delta`.
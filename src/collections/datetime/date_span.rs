@@ -12,6 +12,7 @@ use chrono::{Datelike, NaiveDate, TimeDelta};
 use crate::{
     collections::{base::{Collection, Span, impl_collection}, datetime::DAYS_UNTIL_2000},
     errors::ParseError,
+    try_from_inner,
     utils::from_interval,
 };
 
@@ -27,6 +28,8 @@ impl Drop for DateSpan {
     }
 }
 
+crate::impl_send_sync!(DateSpan);
+
 impl Collection for DateSpan {
     impl_collection!(span, NaiveDate);
 
@@ -317,10 +320,8 @@ impl std::str::FromStr for DateSpan {
     /// assert_eq!(span.upper(), from_ymd_opt(2019, 9, 10));
     /// ```
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        CString::new(string).map_err(|_| ParseError).map(|string| {
-            let inner = unsafe { meos_sys::datespan_in(string.as_ptr()) };
-            Self::from_inner(inner)
-        })
+        let string = CString::new(string).map_err(|_| ParseError)?;
+        try_from_inner(|| unsafe { meos_sys::datespan_in(string.as_ptr()) }, Self::from_inner)
     }
 }
 
@@ -354,6 +355,7 @@ impl cmp::Eq for DateSpan {}
 
 impl From<Range<NaiveDate>> for DateSpan {
     fn from(Range { start, end }: Range<NaiveDate>) -> Self {
+        crate::meos_initialize();
         let inner = unsafe {
             meos_sys::datespan_make(
                 start
@@ -373,6 +375,7 @@ impl From<Range<NaiveDate>> for DateSpan {
 
 impl From<RangeInclusive<NaiveDate>> for DateSpan {
     fn from(range: RangeInclusive<NaiveDate>) -> Self {
+        crate::meos_initialize();
         let inner = unsafe {
             meos_sys::datespan_make(
                 range
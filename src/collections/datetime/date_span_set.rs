@@ -11,6 +11,7 @@ use std::ops::{BitAnd, BitOr};
 use crate::collections::base::SpanSet;
 use crate::collections::base::{Collection, Span, impl_collection, impl_iterator};
 use crate::errors::ParseError;
+use crate::try_from_inner;
 use crate::utils::from_interval;
 
 use super::date_span::DateSpan;
@@ -28,6 +29,8 @@ impl Drop for DateSpanSet {
     }
 }
 
+crate::impl_send_sync!(DateSpanSet);
+
 impl Collection for DateSpanSet {
     impl_collection!(spanset, NaiveDate);
     fn contains(&self, content: &NaiveDate) -> bool {
@@ -272,10 +275,8 @@ impl Hash for DateSpanSet {
 impl std::str::FromStr for DateSpanSet {
     type Err = ParseError;
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        CString::new(string).map_err(|_| ParseError).map(|string| {
-            let inner = unsafe { meos_sys::datespanset_in(string.as_ptr()) };
-            Self::from_inner(inner)
-        })
+        let string = CString::new(string).map_err(|_| ParseError)?;
+        try_from_inner(|| unsafe { meos_sys::datespanset_in(string.as_ptr()) }, Self::from_inner)
     }
 }
 
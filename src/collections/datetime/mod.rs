@@ -6,6 +6,9 @@ pub use date_span::DateSpan;
 mod date_span_set;
 pub use date_span_set::DateSpanSet;
 
+mod tstz_set;
+pub use tstz_set::TsTzSet;
+
 mod tstz_span;
 pub use tstz_span::TsTzSpan;
 
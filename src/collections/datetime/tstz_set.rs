@@ -0,0 +1,145 @@
+use std::ffi::{c_void, CStr, CString};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ptr;
+
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::errors::ParseError;
+use crate::try_from_inner;
+use crate::utils::{from_meos_timestamp, to_meos_timestamp};
+use crate::WKBVariant;
+
+/// An ordered set of distinct timestamps.
+pub struct TsTzSet {
+    _inner: ptr::NonNull<meos_sys::Set>,
+}
+
+impl TsTzSet {
+    pub(crate) fn inner(&self) -> *const meos_sys::Set {
+        self._inner.as_ptr()
+    }
+
+    pub(crate) fn from_inner(inner: *mut meos_sys::Set) -> Self {
+        Self {
+            _inner: ptr::NonNull::new(inner).expect("Null pointers not allowed"),
+        }
+    }
+
+    /// Returns the number of timestamps in the set.
+    #[doc(alias = "set_num_values")]
+    pub fn num_values(&self) -> i32 {
+        unsafe { meos_sys::set_num_values(self.inner()) }
+    }
+
+    /// Returns the first timestamp in the set.
+    #[doc(alias = "tstzset_start_value")]
+    pub fn start_value(&self) -> DateTime<Utc> {
+        from_meos_timestamp(unsafe { meos_sys::tstzset_start_value(self.inner()) })
+    }
+
+    /// Returns the last timestamp in the set.
+    #[doc(alias = "tstzset_end_value")]
+    pub fn end_value(&self) -> DateTime<Utc> {
+        from_meos_timestamp(unsafe { meos_sys::tstzset_end_value(self.inner()) })
+    }
+
+    /// Returns the timestamps in the set, in increasing order.
+    #[doc(alias = "tstzset_values")]
+    pub fn values(&self) -> Vec<DateTime<Utc>> {
+        unsafe {
+            let values = meos_sys::tstzset_values(self.inner());
+            let result = std::slice::from_raw_parts(values, self.num_values() as usize)
+                .iter()
+                .map(|&timestamp| from_meos_timestamp(timestamp))
+                .collect();
+            libc::free(values.cast::<c_void>());
+            result
+        }
+    }
+
+    /// Returns the WKB representation of the set.
+    #[doc(alias = "set_as_wkb")]
+    pub fn as_wkb(&self, variant: WKBVariant) -> Vec<u8> {
+        unsafe {
+            let mut size = 0;
+            let wkb = meos_sys::set_as_wkb(self.inner(), variant.into(), &raw mut size);
+            let result = std::slice::from_raw_parts(wkb, size).to_vec();
+            libc::free(wkb.cast::<c_void>());
+            result
+        }
+    }
+}
+
+impl Drop for TsTzSet {
+    fn drop(&mut self) {
+        unsafe {
+            libc::free(self._inner.as_ptr().cast::<c_void>());
+        }
+    }
+}
+
+crate::impl_send_sync!(TsTzSet);
+
+impl Clone for TsTzSet {
+    fn clone(&self) -> Self {
+        Self::from_inner(unsafe { meos_sys::set_copy(self.inner()) })
+    }
+}
+
+impl Hash for TsTzSet {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let hash = unsafe { meos_sys::set_hash(self.inner()) };
+        state.write_u32(hash);
+
+        let _ = state.finish();
+    }
+}
+
+impl std::str::FromStr for TsTzSet {
+    type Err = ParseError;
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let string = CString::new(string).map_err(|_| ParseError)?;
+        try_from_inner(
+            || unsafe { meos_sys::tstzset_in(string.as_ptr()) },
+            Self::from_inner,
+        )
+    }
+}
+
+impl std::cmp::PartialEq for TsTzSet {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { meos_sys::set_eq(self.inner(), other.inner()) }
+    }
+}
+
+impl Debug for TsTzSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let out_str = unsafe { meos_sys::tstzset_out(self.inner()) };
+        let c_str = unsafe { CStr::from_ptr(out_str) };
+        let str = c_str.to_str().map_err(|_| std::fmt::Error)?;
+        let result = f.write_str(str);
+        unsafe { libc::free(out_str.cast::<c_void>()) };
+        result
+    }
+}
+
+impl<Tz: TimeZone> TryFrom<&[DateTime<Tz>]> for TsTzSet {
+    type Error = ParseError;
+
+    /// Creates a set from `timestamps`, which are sorted and deduplicated.
+    ///
+    /// ## Returns
+    /// An error if `timestamps` is empty.
+    #[doc(alias = "tstzset_make")]
+    fn try_from(timestamps: &[DateTime<Tz>]) -> Result<Self, Self::Error> {
+        crate::meos_initialize();
+        if timestamps.is_empty() {
+            return Err(ParseError);
+        }
+        let timestamps: Vec<_> = timestamps.iter().map(to_meos_timestamp).collect();
+        Ok(Self::from_inner(unsafe {
+            meos_sys::tstzset_make(timestamps.as_ptr(), timestamps.len() as i32)
+        }))
+    }
+}
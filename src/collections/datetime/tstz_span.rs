@@ -12,6 +12,7 @@ use chrono::{DateTime, Datelike, TimeDelta, TimeZone, Utc};
 use crate::{
     collections::{base::{Collection, Span, impl_collection}},
     errors::ParseError,
+    try_from_inner,
     utils::{create_interval, from_interval, from_meos_timestamp, to_meos_timestamp},
     BoundingBox,
 };
@@ -28,6 +29,8 @@ impl Drop for TsTzSpan {
     }
 }
 
+crate::impl_send_sync!(TsTzSpan);
+
 impl Collection for TsTzSpan {
     impl_collection!(span, DateTime<Utc>);
 
@@ -322,10 +325,8 @@ impl std::str::FromStr for TsTzSpan {
     /// assert_eq!(span.upper(), from_ymd_opt(2019, 9, 10));
     /// ```
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        CString::new(string).map_err(|_| ParseError).map(|string| {
-            let inner = unsafe { meos_sys::tstzspan_in(string.as_ptr()) };
-            Self::from_inner(inner)
-        })
+        let string = CString::new(string).map_err(|_| ParseError)?;
+        try_from_inner(|| unsafe { meos_sys::tstzspan_in(string.as_ptr()) }, Self::from_inner)
     }
 }
 
@@ -361,6 +362,7 @@ impl cmp::Eq for TsTzSpan {}
 
 impl<Tz: TimeZone> From<Range<DateTime<Tz>>> for TsTzSpan {
     fn from(Range { start, end }: Range<DateTime<Tz>>) -> Self {
+        crate::meos_initialize();
         let inner = unsafe {
             meos_sys::tstzspan_make(
                 to_meos_timestamp(&start),
@@ -375,6 +377,7 @@ impl<Tz: TimeZone> From<Range<DateTime<Tz>>> for TsTzSpan {
 
 impl<Tz: TimeZone> From<RangeInclusive<DateTime<Tz>>> for TsTzSpan {
     fn from(range: RangeInclusive<DateTime<Tz>>) -> Self {
+        crate::meos_initialize();
         let inner = unsafe {
             meos_sys::tstzspan_make(
                 to_meos_timestamp(range.start()),
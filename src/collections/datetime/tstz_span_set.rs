@@ -11,10 +11,12 @@ use std::ops::{BitAnd, BitOr};
 
 use crate::collections::base::{Collection, Span, SpanSet, impl_collection, impl_iterator};
 use crate::errors::ParseError;
+use crate::try_from_inner;
 use crate::utils::to_meos_timestamp;
 
 use super::tstz_span::TsTzSpan;
 use crate::utils::create_interval;
+use crate::utils::from_interval;
 
 pub struct TsTzSpanSet {
     _inner: ptr::NonNull<meos_sys::SpanSet>,
@@ -28,6 +30,8 @@ impl Drop for TsTzSpanSet {
     }
 }
 
+crate::impl_send_sync!(TsTzSpanSet);
+
 impl Collection for TsTzSpanSet {
     impl_collection!(spanset, DateTime<Utc>);
     fn contains(&self, content: &DateTime<Utc>) -> bool {
@@ -243,6 +247,22 @@ impl SpanSet for TsTzSpanSet {
     }
 }
 
+impl TsTzSpanSet {
+    /// Returns the duration of the span set.
+    ///
+    /// ## Arguments
+    /// * `ignore_gaps` - Whether to return the duration of the bounding span instead of the sum of the spans.
+    ///
+    /// ## Returns
+    /// The duration as a `TimeDelta`.
+    #[doc(alias = "tstzspanset_duration")]
+    pub fn duration(&self, ignore_gaps: bool) -> TimeDelta {
+        from_interval(unsafe {
+            meos_sys::tstzspanset_duration(self.inner(), ignore_gaps).read()
+        })
+    }
+}
+
 impl Clone for TsTzSpanSet {
     fn clone(&self) -> TsTzSpanSet {
         self.copy()
@@ -263,10 +283,8 @@ impl Hash for TsTzSpanSet {
 impl std::str::FromStr for TsTzSpanSet {
     type Err = ParseError;
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        CString::new(string).map_err(|_| ParseError).map(|string| {
-            let inner = unsafe { meos_sys::tstzspanset_in(string.as_ptr()) };
-            Self::from_inner(inner)
-        })
+        let string = CString::new(string).map_err(|_| ParseError)?;
+        try_from_inner(|| unsafe { meos_sys::tstzspanset_in(string.as_ptr()) }, Self::from_inner)
     }
 }
 
@@ -10,6 +10,7 @@ use std::{
 use crate::{
     collections::base::{impl_collection, Collection, Span},
     errors::ParseError,
+    try_from_inner,
 };
 
 use super::number_span::NumberSpan;
@@ -26,6 +27,8 @@ impl Drop for FloatSpan {
     }
 }
 
+crate::impl_send_sync!(FloatSpan);
+
 impl Collection for FloatSpan {
     impl_collection!(span, f64);
     fn contains(&self, content: &f64) -> bool {
@@ -170,6 +173,35 @@ impl Span for FloatSpan {
 
 impl NumberSpan for FloatSpan {}
 
+impl FloatSpan {
+    /// Returns a new `FloatSpan` with the bounds rounded down to the nearest integer.
+    #[doc(alias = "floatspan_floor")]
+    pub fn floor(&self) -> FloatSpan {
+        FloatSpan::from_inner(unsafe { meos_sys::floatspan_floor(self.inner()) })
+    }
+
+    /// Returns a new `FloatSpan` with the bounds rounded up to the nearest integer.
+    #[doc(alias = "floatspan_ceil")]
+    pub fn ceil(&self) -> FloatSpan {
+        FloatSpan::from_inner(unsafe { meos_sys::floatspan_ceil(self.inner()) })
+    }
+
+    /// Returns a new `FloatSpan` with the bounds converted from radians to degrees.
+    ///
+    /// # Arguments
+    /// * `normalize` - Whether the result is normalized to the range [0, 360).
+    #[doc(alias = "floatspan_degrees")]
+    pub fn degrees(&self, normalize: bool) -> FloatSpan {
+        FloatSpan::from_inner(unsafe { meos_sys::floatspan_degrees(self.inner(), normalize) })
+    }
+
+    /// Returns a new `FloatSpan` with the bounds converted from degrees to radians.
+    #[doc(alias = "floatspan_radians")]
+    pub fn radians(&self) -> FloatSpan {
+        FloatSpan::from_inner(unsafe { meos_sys::floatspan_radians(self.inner()) })
+    }
+}
+
 impl Clone for FloatSpan {
     fn clone(&self) -> Self {
         unsafe { Self::from_inner(meos_sys::span_copy(self.inner())) }
@@ -209,10 +241,11 @@ impl std::str::FromStr for FloatSpan {
     /// assert_eq!(span.upper(), 67.8);
     /// ```
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        CString::new(string).map_err(|_| ParseError).map(|string| {
-            let inner = unsafe { meos_sys::floatspan_in(string.as_ptr()) };
-            Self::from_inner(inner)
-        })
+        let string = CString::new(string).map_err(|_| ParseError)?;
+        try_from_inner(
+            || unsafe { meos_sys::floatspan_in(string.as_ptr()) },
+            Self::from_inner,
+        )
     }
 }
 
@@ -244,6 +277,7 @@ impl cmp::Eq for FloatSpan {}
 
 impl From<Range<f64>> for FloatSpan {
     fn from(Range { start, end }: Range<f64>) -> Self {
+        crate::meos_initialize();
         let inner = unsafe { meos_sys::floatspan_make(start, end, true, false) };
         Self::from_inner(inner)
     }
@@ -251,6 +285,7 @@ impl From<Range<f64>> for FloatSpan {
 
 impl From<Range<f32>> for FloatSpan {
     fn from(Range { start, end }: Range<f32>) -> Self {
+        crate::meos_initialize();
         let inner =
             unsafe { meos_sys::floatspan_make(f64::from(start), f64::from(end), true, false) };
         Self::from_inner(inner)
@@ -259,6 +294,7 @@ impl From<Range<f32>> for FloatSpan {
 
 impl From<RangeInclusive<f64>> for FloatSpan {
     fn from(range: RangeInclusive<f64>) -> Self {
+        crate::meos_initialize();
         let inner = unsafe { meos_sys::floatspan_make(*range.start(), *range.end(), true, true) };
         Self::from_inner(inner)
     }
@@ -266,6 +302,7 @@ impl From<RangeInclusive<f64>> for FloatSpan {
 
 impl From<RangeInclusive<f32>> for FloatSpan {
     fn from(range: RangeInclusive<f32>) -> Self {
+        crate::meos_initialize();
         let inner = unsafe {
             meos_sys::floatspan_make(
                 f64::from(*range.start()),
@@ -9,6 +9,7 @@ use crate::collections::base::Span;
 use crate::collections::base::SpanSet;
 use crate::collections::base::{Collection, impl_collection, impl_iterator};
 use crate::errors::ParseError;
+use crate::try_from_inner;
 
 use super::float_span::FloatSpan;
 use super::number_span_set::NumberSpanSet;
@@ -25,6 +26,8 @@ impl Drop for FloatSpanSet {
     }
 }
 
+crate::impl_send_sync!(FloatSpanSet);
+
 impl Collection for FloatSpanSet {
     impl_collection!(spanset, f64);
 
@@ -202,6 +205,35 @@ impl SpanSet for FloatSpanSet {
 
 impl NumberSpanSet for FloatSpanSet {}
 
+impl FloatSpanSet {
+    /// Returns a new `FloatSpanSet` with the bounds rounded down to the nearest integer.
+    #[doc(alias = "floatspanset_floor")]
+    pub fn floor(&self) -> FloatSpanSet {
+        FloatSpanSet::from_inner(unsafe { meos_sys::floatspanset_floor(self.inner()) })
+    }
+
+    /// Returns a new `FloatSpanSet` with the bounds rounded up to the nearest integer.
+    #[doc(alias = "floatspanset_ceil")]
+    pub fn ceil(&self) -> FloatSpanSet {
+        FloatSpanSet::from_inner(unsafe { meos_sys::floatspanset_ceil(self.inner()) })
+    }
+
+    /// Returns a new `FloatSpanSet` with the bounds converted from radians to degrees.
+    ///
+    /// # Arguments
+    /// * `normalize` - Whether the result is normalized to the range [0, 360).
+    #[doc(alias = "floatspanset_degrees")]
+    pub fn degrees(&self, normalize: bool) -> FloatSpanSet {
+        FloatSpanSet::from_inner(unsafe { meos_sys::floatspanset_degrees(self.inner(), normalize) })
+    }
+
+    /// Returns a new `FloatSpanSet` with the bounds converted from degrees to radians.
+    #[doc(alias = "floatspanset_radians")]
+    pub fn radians(&self) -> FloatSpanSet {
+        FloatSpanSet::from_inner(unsafe { meos_sys::floatspanset_radians(self.inner()) })
+    }
+}
+
 impl Clone for FloatSpanSet {
     fn clone(&self) -> FloatSpanSet {
         self.copy()
@@ -222,10 +254,8 @@ impl Hash for FloatSpanSet {
 impl std::str::FromStr for FloatSpanSet {
     type Err = ParseError;
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        CString::new(string).map_err(|_| ParseError).map(|string| {
-            let inner = unsafe { meos_sys::floatspanset_in(string.as_ptr()) };
-            Self::from_inner(inner)
-        })
+        let string = CString::new(string).map_err(|_| ParseError)?;
+        try_from_inner(|| unsafe { meos_sys::floatspanset_in(string.as_ptr()) }, Self::from_inner)
     }
 }
 
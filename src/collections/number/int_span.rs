@@ -10,6 +10,7 @@ use std::{
 use crate::{
     collections::base::{impl_collection, Collection, Span},
     errors::ParseError,
+    try_from_inner,
 };
 
 use super::number_span::NumberSpan;
@@ -26,6 +27,8 @@ impl Drop for IntSpan {
     }
 }
 
+crate::impl_send_sync!(IntSpan);
+
 impl Collection for IntSpan {
     impl_collection!(span, i32);
     fn contains(&self, content: &i32) -> bool {
@@ -246,10 +249,11 @@ impl std::str::FromStr for IntSpan {
     /// assert_eq!(span.upper(), 67);
     /// ```
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        CString::new(string).map_err(|_| ParseError).map(|string| {
-            let inner = unsafe { meos_sys::intspan_in(string.as_ptr()) };
-            Self::from_inner(inner)
-        })
+        let string = CString::new(string).map_err(|_| ParseError)?;
+        try_from_inner(
+            || unsafe { meos_sys::intspan_in(string.as_ptr()) },
+            Self::from_inner,
+        )
     }
 }
 
@@ -281,6 +285,7 @@ impl cmp::Eq for IntSpan {}
 
 impl From<Range<i32>> for IntSpan {
     fn from(Range { start, end }: Range<i32>) -> Self {
+        crate::meos_initialize();
         let inner = unsafe { meos_sys::intspan_make(start, end, true, false) };
         Self::from_inner(inner)
     }
@@ -288,6 +293,7 @@ impl From<Range<i32>> for IntSpan {
 
 impl From<RangeInclusive<i32>> for IntSpan {
     fn from(range: RangeInclusive<i32>) -> Self {
+        crate::meos_initialize();
         let inner = unsafe { meos_sys::intspan_make(*range.start(), *range.end(), true, true) };
         Self::from_inner(inner)
     }
@@ -295,6 +301,7 @@ impl From<RangeInclusive<i32>> for IntSpan {
 
 impl From<RangeInclusive<f32>> for IntSpan {
     fn from(range: RangeInclusive<f32>) -> Self {
+        crate::meos_initialize();
         let inner = unsafe {
             meos_sys::intspan_make(*range.start() as i32, *range.end() as i32, true, true)
         };
@@ -8,6 +8,7 @@ use std::ptr;
 use crate::collections::base::SpanSet;
 use crate::collections::base::{Collection, Span, impl_collection, impl_iterator};
 use crate::errors::ParseError;
+use crate::try_from_inner;
 
 use super::int_span::IntSpan;
 use super::number_span_set::NumberSpanSet;
@@ -24,6 +25,8 @@ impl Drop for IntSpanSet {
     }
 }
 
+crate::impl_send_sync!(IntSpanSet);
+
 impl Collection for IntSpanSet {
     impl_collection!(spanset, i32);
     fn contains(&self, content: &i32) -> bool {
@@ -220,10 +223,8 @@ impl Hash for IntSpanSet {
 impl std::str::FromStr for IntSpanSet {
     type Err = ParseError;
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        CString::new(string).map_err(|_| ParseError).map(|string| {
-            let inner = unsafe { meos_sys::intspanset_in(string.as_ptr()) };
-            Self::from_inner(inner)
-        })
+        let string = CString::new(string).map_err(|_| ParseError)?;
+        try_from_inner(|| unsafe { meos_sys::intspanset_in(string.as_ptr()) }, Self::from_inner)
     }
 }
 
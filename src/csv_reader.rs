@@ -0,0 +1,525 @@
+//! Reading trajectories from CSV files with one row per observation.
+//!
+//! Files whose rows are grouped by entity are streamed with bounded memory,
+//! see [`CsvTrajectoryOptions::grouped`]. Other files are fully buffered.
+//!
+//! ## Example
+//! ```no_run
+//! # use meos::csv_reader::{CsvTrajectoryOptions, CsvTrajectoryReader};
+//! # meos::meos_initialize();
+//! let options = CsvTrajectoryOptions::new("mmsi", "t", "longitude", "latitude")
+//!     .float_column("sog")
+//!     .time_format("%Y-%m-%d %H:%M:%S")
+//!     .srid(4326);
+//! for trajectory in CsvTrajectoryReader::from_path("data/ais_instants.csv", options).unwrap() {
+//!     let trajectory = trajectory.unwrap();
+//!     println!("{}: {:?}", trajectory.id, trajectory.float_properties.get("sog"));
+//! }
+//! ```
+
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    error, fmt,
+    fs::File,
+    io,
+    path::Path,
+};
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use csv::{ReaderBuilder, StringRecord, StringRecordsIntoIter};
+
+use crate::{
+    Point, TFloatInstant, TFloatSequence, TGeomPointInstant, TGeomPointSequence, TInstant,
+    TInterpolation, TPointTrait, TSequence, TSequenceBuilder, TSequenceBuilderError, TTextInstant,
+    TTextSequence,
+};
+
+/// Error returned while reading trajectories from a CSV file.
+#[derive(Debug)]
+pub enum CsvTrajectoryError {
+    /// The file could not be read or is not valid CSV.
+    Csv(csv::Error),
+    /// A configured column is not present in the header.
+    MissingColumn(String),
+    /// A row has a missing or invalid value in a column.
+    InvalidValue { line: u64, column: String },
+    /// The observations of an entity do not make a valid sequence.
+    InvalidSequence {
+        id: String,
+        error: TSequenceBuilderError,
+    },
+}
+
+impl fmt::Display for CsvTrajectoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Csv(error) => write!(f, "{error}"),
+            Self::MissingColumn(column) => write!(f, "missing column `{column}`"),
+            Self::InvalidValue { line, column } => {
+                write!(f, "invalid value in column `{column}` at line {line}")
+            }
+            Self::InvalidSequence { id, error } => {
+                write!(f, "invalid sequence of entity `{id}`: {error}")
+            }
+        }
+    }
+}
+
+impl error::Error for CsvTrajectoryError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Csv(error) => Some(error),
+            Self::InvalidSequence { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<csv::Error> for CsvTrajectoryError {
+    fn from(error: csv::Error) -> Self {
+        Self::Csv(error)
+    }
+}
+
+/// Column mapping and parsing options of a [`CsvTrajectoryReader`].
+///
+/// Column names are matched case-insensitively against the header of the file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvTrajectoryOptions {
+    pub(crate) id: String,
+    pub(crate) time: String,
+    pub(crate) x: String,
+    pub(crate) y: String,
+    pub(crate) z: Option<String>,
+    pub(crate) float_columns: Vec<String>,
+    pub(crate) text_columns: Vec<String>,
+    pub(crate) time_format: Option<String>,
+    pub(crate) srid: i32,
+    pub(crate) delimiter: u8,
+    pub(crate) grouped: bool,
+    pub(crate) skip_invalid_rows: bool,
+}
+
+impl CsvTrajectoryOptions {
+    /// Creates the options of a file with the given entity id, time and coordinate columns.
+    pub fn new(
+        id: impl Into<String>,
+        time: impl Into<String>,
+        x: impl Into<String>,
+        y: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            time: time.into(),
+            x: x.into(),
+            y: y.into(),
+            z: None,
+            float_columns: Vec::new(),
+            text_columns: Vec::new(),
+            time_format: None,
+            srid: 0,
+            delimiter: b',',
+            grouped: false,
+            skip_invalid_rows: false,
+        }
+    }
+
+    /// Sets the column of the Z coordinate.
+    pub fn z(mut self, column: impl Into<String>) -> Self {
+        self.z = Some(column.into());
+        self
+    }
+
+    /// Adds a numeric column, read as a linear `TFloatSequence` per entity.
+    pub fn float_column(mut self, column: impl Into<String>) -> Self {
+        self.float_columns.push(column.into());
+        self
+    }
+
+    /// Adds a text column, read as a step `TTextSequence` per entity.
+    pub fn text_column(mut self, column: impl Into<String>) -> Self {
+        self.text_columns.push(column.into());
+        self
+    }
+
+    /// Sets the `chrono` format of the time column, e.g. `"%Y-%m-%d %H:%M:%S"` or
+    /// `"%s"` for Unix timestamps. Times without offset are assumed to be in UTC.
+    ///
+    /// By default, times are parsed as RFC 3339 or as `%Y-%m-%d %H:%M:%S%.f` in UTC.
+    pub fn time_format(mut self, format: impl Into<String>) -> Self {
+        self.time_format = Some(format.into());
+        self
+    }
+
+    /// Sets the SRID of the trajectories.
+    pub fn srid(mut self, srid: i32) -> Self {
+        self.srid = srid;
+        self
+    }
+
+    /// Sets the field delimiter, `,` by default.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets whether the rows of each entity are contiguous in the file.
+    ///
+    /// Grouped mode is the streaming mode: the trajectory of an entity is
+    /// yielded as soon as a row of another entity is read, so only one entity
+    /// is held in memory. Otherwise, all the rows of the file are buffered
+    /// before the first trajectory is yielded.
+    pub fn grouped(mut self, grouped: bool) -> Self {
+        self.grouped = grouped;
+        self
+    }
+
+    /// Sets whether rows with missing or invalid values are skipped instead of
+    /// returning an error. Empty values of the float and text columns are never
+    /// an error, they are just left out of the corresponding sequence.
+    pub fn skip_invalid_rows(mut self, skip_invalid_rows: bool) -> Self {
+        self.skip_invalid_rows = skip_invalid_rows;
+        self
+    }
+}
+
+/// The trajectory of an entity read from a CSV file, together with the
+/// sequences of its float and text columns keyed by column name.
+#[derive(Debug)]
+pub struct CsvTrajectory {
+    pub id: String,
+    pub trajectory: TGeomPointSequence,
+    pub float_properties: BTreeMap<String, TFloatSequence>,
+    pub text_properties: BTreeMap<String, TTextSequence>,
+}
+
+struct Columns {
+    id: usize,
+    time: usize,
+    x: usize,
+    y: usize,
+    z: Option<usize>,
+    floats: Vec<usize>,
+    texts: Vec<usize>,
+}
+
+struct Observation {
+    time: DateTime<Utc>,
+    point: Point,
+    floats: Vec<Option<f64>>,
+    texts: Vec<Option<String>>,
+}
+
+struct Entity {
+    id: String,
+    observations: Vec<Observation>,
+}
+
+/// Streaming reader of the trajectories of a CSV file, one per entity id.
+///
+/// Each trajectory is a linear sequence with the observations of its entity
+/// ordered by time. Observations with a repeated timestamp are ignored. The
+/// trajectory and the property sequences have inclusive bounds, so they are
+/// defined at the timestamp of every observation, including the last one.
+///
+/// Unless the options are [`grouped`](CsvTrajectoryOptions::grouped), the
+/// whole file is read and buffered by the first call to `next`.
+pub struct CsvTrajectoryReader<R> {
+    records: StringRecordsIntoIter<R>,
+    columns: Columns,
+    options: CsvTrajectoryOptions,
+    ids: HashMap<String, usize>,
+    entities: Vec<Entity>,
+    ready: VecDeque<Entity>,
+    skipped_rows: u64,
+}
+
+impl CsvTrajectoryReader<File> {
+    /// Opens the CSV file at `path`.
+    pub fn from_path(
+        path: impl AsRef<Path>,
+        options: CsvTrajectoryOptions,
+    ) -> Result<Self, CsvTrajectoryError> {
+        let file = File::open(path).map_err(|error| CsvTrajectoryError::Csv(error.into()))?;
+        Self::new(file, options)
+    }
+}
+
+impl<R: io::Read> CsvTrajectoryReader<R> {
+    /// Creates a reader of the CSV data of `reader`, whose first row must be the header.
+    pub fn new(reader: R, options: CsvTrajectoryOptions) -> Result<Self, CsvTrajectoryError> {
+        let mut reader = ReaderBuilder::new()
+            .delimiter(options.delimiter)
+            .flexible(true)
+            .from_reader(reader);
+        let headers = reader.headers()?;
+        let index = |column: &String| {
+            headers
+                .iter()
+                .position(|header| header.trim().eq_ignore_ascii_case(column))
+                .ok_or_else(|| CsvTrajectoryError::MissingColumn(column.clone()))
+        };
+        let columns = Columns {
+            id: index(&options.id)?,
+            time: index(&options.time)?,
+            x: index(&options.x)?,
+            y: index(&options.y)?,
+            z: options.z.as_ref().map(index).transpose()?,
+            floats: options
+                .float_columns
+                .iter()
+                .map(index)
+                .collect::<Result<_, _>>()?,
+            texts: options
+                .text_columns
+                .iter()
+                .map(index)
+                .collect::<Result<_, _>>()?,
+        };
+        Ok(Self {
+            records: reader.into_records(),
+            columns,
+            options,
+            ids: HashMap::new(),
+            entities: Vec::new(),
+            ready: VecDeque::new(),
+            skipped_rows: 0,
+        })
+    }
+
+    /// Returns the number of rows skipped so far because of missing or invalid values.
+    pub fn skipped_rows(&self) -> u64 {
+        self.skipped_rows
+    }
+
+    fn parse(&self, record: &StringRecord) -> Result<(String, Observation), CsvTrajectoryError> {
+        let invalid = |column: &String| CsvTrajectoryError::InvalidValue {
+            line: record.position().map_or(0, csv::Position::line),
+            column: column.clone(),
+        };
+        let field = |index: usize| record.get(index).map(str::trim).filter(|f| !f.is_empty());
+        let number = |index: usize, column: &String| {
+            field(index)
+                .map(|value| value.parse::<f64>().map_err(|_| invalid(column)))
+                .transpose()
+        };
+
+        let id = field(self.columns.id).ok_or_else(|| invalid(&self.options.id))?;
+        let time = field(self.columns.time)
+            .and_then(|time| parse_time(time, self.options.time_format.as_deref()))
+            .ok_or_else(|| invalid(&self.options.time))?;
+        let x = number(self.columns.x, &self.options.x)?.ok_or_else(|| invalid(&self.options.x))?;
+        let y = number(self.columns.y, &self.options.y)?.ok_or_else(|| invalid(&self.options.y))?;
+        let z = match (self.columns.z, &self.options.z) {
+            (Some(index), Some(column)) => {
+                Some(number(index, column)?.ok_or_else(|| invalid(column))?)
+            }
+            _ => None,
+        };
+        let floats = self
+            .columns
+            .floats
+            .iter()
+            .zip(&self.options.float_columns)
+            .map(|(&index, column)| number(index, column))
+            .collect::<Result<_, _>>()?;
+        let texts = self
+            .columns
+            .texts
+            .iter()
+            .map(|&index| field(index).map(str::to_owned))
+            .collect();
+        Ok((
+            id.to_owned(),
+            Observation {
+                time,
+                point: Point(x, y, z),
+                floats,
+                texts,
+            },
+        ))
+    }
+
+    fn push(&mut self, id: String, observation: Observation) {
+        if let Some(&index) = self.ids.get(&id) {
+            self.entities[index].observations.push(observation);
+            return;
+        }
+        if self.options.grouped {
+            self.flush();
+        }
+        self.ids.insert(id.clone(), self.entities.len());
+        self.entities.push(Entity {
+            id,
+            observations: vec![observation],
+        });
+    }
+
+    fn flush(&mut self) {
+        self.ready.extend(self.entities.drain(..));
+        self.ids.clear();
+    }
+
+    fn build(&self, mut entity: Entity) -> Result<CsvTrajectory, CsvTrajectoryError> {
+        entity
+            .observations
+            .sort_by_key(|observation| observation.time);
+        entity
+            .observations
+            .dedup_by_key(|observation| observation.time);
+        let observations = &entity.observations;
+        let invalid = |error| CsvTrajectoryError::InvalidSequence {
+            id: entity.id.clone(),
+            error,
+        };
+
+        let mut trajectory: TGeomPointSequence = inclusive_sequence(
+            observations
+                .iter()
+                .map(|o| TGeomPointInstant::from_value_and_timestamp(o.point, o.time)),
+            TInterpolation::Linear,
+        )
+        .map_err(invalid)?;
+        if self.options.srid != 0 {
+            trajectory = trajectory.with_srid(self.options.srid);
+        }
+
+        let mut float_properties = BTreeMap::new();
+        for (i, column) in self.options.float_columns.iter().enumerate() {
+            let instants = observations.iter().filter_map(|o| {
+                Some(TFloatInstant::from_value_and_timestamp(
+                    o.floats[i]?,
+                    o.time,
+                ))
+            });
+            match inclusive_sequence(instants, TInterpolation::Linear) {
+                Ok(sequence) => {
+                    float_properties.insert(column.clone(), sequence);
+                }
+                Err(TSequenceBuilderError::Empty) => {}
+                Err(error) => return Err(invalid(error)),
+            }
+        }
+        let mut text_properties = BTreeMap::new();
+        for (i, column) in self.options.text_columns.iter().enumerate() {
+            let instants = observations.iter().filter_map(|o| {
+                Some(TTextInstant::from_value_and_timestamp(
+                    o.texts[i].clone()?,
+                    o.time,
+                ))
+            });
+            match inclusive_sequence(instants, TInterpolation::Stepwise) {
+                Ok(sequence) => {
+                    text_properties.insert(column.clone(), sequence);
+                }
+                Err(TSequenceBuilderError::Empty) => {}
+                Err(error) => return Err(invalid(error)),
+            }
+        }
+
+        Ok(CsvTrajectory {
+            id: entity.id,
+            trajectory,
+            float_properties,
+            text_properties,
+        })
+    }
+}
+
+/// Builds a sequence with inclusive bounds from instants ordered by time.
+fn inclusive_sequence<S: TSequence>(
+    instants: impl IntoIterator<Item = S::TI>,
+    interpolation: TInterpolation,
+) -> Result<S, TSequenceBuilderError>
+where
+    S::TI: AsRef<S::TI>,
+{
+    let mut builder = TSequenceBuilder::new(interpolation).upper_inclusive(true);
+    for instant in instants {
+        builder.push(instant)?;
+    }
+    builder.build()
+}
+
+impl<R: io::Read> Iterator for CsvTrajectoryReader<R> {
+    type Item = Result<CsvTrajectory, CsvTrajectoryError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(entity) = self.ready.pop_front() {
+                return Some(self.build(entity));
+            }
+            match self.records.next() {
+                Some(Ok(record)) => match self.parse(&record) {
+                    Ok((id, observation)) => self.push(id, observation),
+                    Err(_) if self.options.skip_invalid_rows => self.skipped_rows += 1,
+                    Err(error) => return Some(Err(error)),
+                },
+                Some(Err(error)) => return Some(Err(error.into())),
+                None if self.entities.is_empty() => return None,
+                None => self.flush(),
+            }
+        }
+    }
+}
+
+fn parse_time(time: &str, format: Option<&str>) -> Option<DateTime<Utc>> {
+    match format {
+        Some(format) => DateTime::parse_from_str(time, format)
+            .map(|time| time.to_utc())
+            .or_else(|_| NaiveDateTime::parse_from_str(time, format).map(|time| time.and_utc())),
+        None => DateTime::parse_from_rfc3339(time)
+            .map(|time| time.to_utc())
+            .or_else(|_| {
+                NaiveDateTime::parse_from_str(time, "%Y-%m-%d %H:%M:%S%.f")
+                    .map(|time| time.and_utc())
+            }),
+    }
+    .ok()
+}
+
+#[cfg(test)]
+#[serial_test::serial]
+mod tests {
+    use super::*;
+    use crate::{meos_initialize, meos_initialize_timezone, Temporal};
+
+    #[test]
+    fn read_trajectories() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let csv = "\
+T,MMSI,Latitude,Longitude,SOG,Status
+2020-01-01 00:00:10,1,50.5,4.5,,moored
+2020-01-01 00:00:00,1,50.0,4.0,2.5,
+2020-01-01 00:00:00,2,51.0,5.0,1.0,moving
+not a time,2,51.0,5.0,1.0,moving
+";
+        let options = CsvTrajectoryOptions::new("mmsi", "t", "longitude", "latitude")
+            .float_column("sog")
+            .text_column("status")
+            .srid(4326)
+            .skip_invalid_rows(true);
+        let mut reader = CsvTrajectoryReader::new(csv.as_bytes(), options).unwrap();
+        let first = reader.next().unwrap().unwrap();
+        assert_eq!(first.id, "1");
+        assert_eq!(first.trajectory.num_instants(), 2);
+        assert_eq!(first.trajectory.start_value(), Point(4.0, 50.0, None));
+        assert_eq!(first.trajectory.srid(), 4326);
+        assert_eq!(first.float_properties["sog"].num_instants(), 1);
+        let last = first.trajectory.end_timestamp();
+        assert!(first.trajectory.is_upper_inclusive());
+        assert_eq!(
+            first.trajectory.value_at_timestamp(last),
+            Some(Point(4.5, 50.5, None))
+        );
+        assert_eq!(
+            first.text_properties["status"].value_at_timestamp(last),
+            Some("moored".to_owned())
+        );
+        assert_eq!(first.text_properties["status"].start_value(), "moored");
+        assert_eq!(reader.next().unwrap().unwrap().id, "2");
+        assert!(reader.next().is_none());
+        assert_eq!(reader.skipped_rows(), 1);
+    }
+}
@@ -0,0 +1,340 @@
+//! Parallel batch operations over slices of temporal values, built on [`rayon`].
+//!
+//! `meos` keeps its state per thread, so every helper initializes it on the
+//! worker threads before running any user code, which can then freely call
+//! into this crate (or into the raw [`crate::sys`] bindings).
+//!
+//! Aggregations are computed by folding each chunk of the input into a
+//! partial state, such as [`TCount`] or [`TExtent`], and merging the partial
+//! states of the chunks afterwards.
+//!
+//! ## Example
+//! ```
+//! # use meos::{meos_initialize, parallel, TGeomPoint, TPointTrait as _};
+//! # meos_initialize();
+//! let trips: Vec<TGeomPoint> = vec![
+//!     "[POINT(0 0)@2020-01-01, POINT(1 1)@2020-01-02]".parse().unwrap(),
+//!     "[POINT(1 1)@2020-01-01, POINT(3 3)@2020-01-03]".parse().unwrap(),
+//! ];
+//! let lengths = parallel::par_map(&trips, |trip| trip.length());
+//! let count = parallel::par_aggregate::<parallel::TCount, _>(&trips).unwrap();
+//! ```
+
+use std::{error, fmt, ptr};
+
+use rayon::prelude::*;
+
+use crate::{
+    catch_meos_error, factory, meos_initialize, BoundingBox, Collection, MeosBox, STBox, Span,
+    TBox, TInt, Temporal, TsTzSpan,
+};
+
+/// Applies `f` to every value of `values` in parallel.
+///
+/// ## Returns
+/// The results, in the order of `values`.
+pub fn par_map<T, R, F>(values: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    values
+        .par_iter()
+        .map(|value| {
+            meos_initialize();
+            f(value)
+        })
+        .collect()
+}
+
+/// Returns the values of `values` whose spatiotemporal bounding box overlaps
+/// `stbox`, in their original order.
+///
+/// ## Arguments
+/// * `values` - The temporal points to filter.
+/// * `stbox` - The box to filter them with. Only the dimensions it has are compared.
+#[doc(alias = "overlaps_stbox_stbox")]
+pub fn par_filter_by_stbox<'a, T>(values: &'a [T], stbox: &STBox) -> Vec<&'a T>
+where
+    T: Temporal<TBB = STBox> + Sync,
+{
+    values
+        .par_iter()
+        .filter(|value| {
+            meos_initialize();
+            value.bounding_box().overlaps(stbox)
+        })
+        .collect()
+}
+
+/// Partial state of an aggregation over temporal values of type `T`, which
+/// can be computed over disjoint parts of the input and merged afterwards.
+pub trait PartialAggregate<T>: Default + Send {
+    type Output;
+
+    /// Adds `value` to the aggregation.
+    fn add(&mut self, value: &T);
+
+    /// Merges the states of two disjoint parts of the input.
+    fn merge(self, other: Self) -> Self;
+
+    /// Returns the result of the aggregation, or `None` if no value was added.
+    fn finish(self) -> Option<Self::Output>;
+}
+
+/// Aggregates `values` in parallel with the partial aggregation `A`.
+///
+/// ## Example
+/// ```
+/// # use meos::{meos_initialize, parallel::{par_aggregate, TExtent}, TBox, TFloat};
+/// # meos_initialize();
+/// let values: Vec<TFloat> = vec!["[1@2020-01-01, 5@2020-01-02]".parse().unwrap()];
+/// let extent: TBox = par_aggregate::<TExtent<_>, _>(&values).unwrap().unwrap();
+/// ```
+pub fn par_aggregate<A, T>(values: &[T]) -> Option<A::Output>
+where
+    A: PartialAggregate<T>,
+    T: Sync,
+{
+    values
+        .par_iter()
+        .fold(A::default, |mut state, value| {
+            meos_initialize();
+            state.add(value);
+            state
+        })
+        .reduce(A::default, |state, other| {
+            meos_initialize();
+            state.merge(other)
+        })
+        .finish()
+}
+
+/// Partial state of the temporal count of temporal values, that is, the
+/// number of values defined at each instant.
+pub struct TCount {
+    state: *mut meos_sys::SkipList,
+    merged: Option<TInt>,
+}
+
+impl TCount {
+    /// Finalizes the pending skip list of `self` and adds it to the counts
+    /// merged so far.
+    fn take_partial(&mut self) -> Option<TInt> {
+        let state = std::mem::replace(&mut self.state, ptr::null_mut());
+        let count = if state.is_null() {
+            None
+        } else {
+            let result = unsafe { meos_sys::temporal_tagg_finalfn(state) };
+            (!result.is_null()).then(|| factory::<TInt>(result))
+        };
+        sum(self.merged.take(), count)
+    }
+}
+
+/// Returns the temporal sum of two partial counts.
+#[doc(alias = "tint_tsum_transfn")]
+fn sum(first: Option<TInt>, second: Option<TInt>) -> Option<TInt> {
+    match (first, second) {
+        (Some(first), Some(second)) => {
+            let result = unsafe {
+                let state = meos_sys::tint_tsum_transfn(ptr::null_mut(), first.inner());
+                let state = meos_sys::tint_tsum_transfn(state, second.inner());
+                meos_sys::temporal_tagg_finalfn(state)
+            };
+            Some(factory::<TInt>(result))
+        }
+        (first, second) => first.or(second),
+    }
+}
+
+impl Default for TCount {
+    fn default() -> Self {
+        Self {
+            state: ptr::null_mut(),
+            merged: None,
+        }
+    }
+}
+
+impl Drop for TCount {
+    fn drop(&mut self) {
+        // `meos` does not expose a function freeing a skip list, finalizing it does.
+        self.take_partial();
+    }
+}
+
+// The skip list is exclusively owned by the state and only accessed through `&mut self`.
+unsafe impl Send for TCount {}
+
+impl<T: Temporal> PartialAggregate<T> for TCount {
+    type Output = TInt;
+
+    #[doc(alias = "temporal_tcount_transfn")]
+    fn add(&mut self, value: &T) {
+        self.state = unsafe { meos_sys::temporal_tcount_transfn(self.state, value.inner()) };
+    }
+
+    fn merge(mut self, mut other: Self) -> Self {
+        Self {
+            state: ptr::null_mut(),
+            merged: sum(self.take_partial(), other.take_partial()),
+        }
+    }
+
+    fn finish(mut self) -> Option<TInt> {
+        self.take_partial()
+    }
+}
+
+/// Bounding boxes that can be merged into the smallest box containing both.
+pub trait Extent: BoundingBox + Send {
+    /// Returns the smallest box containing `self` and `other`, or `None` if
+    /// they have different dimensions.
+    fn extend(&self, other: &Self) -> Option<Self>;
+}
+
+impl Extent for TsTzSpan {
+    #[doc(alias = "span_extent_transfn")]
+    fn extend(&self, other: &Self) -> Option<Self> {
+        Some(Self::from_inner(unsafe {
+            meos_sys::span_extent_transfn(meos_sys::span_copy(self.inner()), other.inner())
+        }))
+    }
+}
+
+impl Extent for TBox {
+    #[doc(alias = "union_tbox_tbox")]
+    fn extend(&self, other: &Self) -> Option<Self> {
+        catch_meos_error(|| self.union(other, false)).ok().flatten()
+    }
+}
+
+impl Extent for STBox {
+    #[doc(alias = "union_stbox_stbox")]
+    fn extend(&self, other: &Self) -> Option<Self> {
+        catch_meos_error(|| self.union(other, false)).ok().flatten()
+    }
+}
+
+/// Error returned by [`TExtent`] when the bounding boxes of the values have
+/// different dimensions, e.g. 2D and 3D points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DimensionMismatch;
+
+impl fmt::Display for DimensionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "bounding boxes with different dimensions")
+    }
+}
+
+impl error::Error for DimensionMismatch {}
+
+/// Partial state of the extent of temporal values, that is, the smallest
+/// bounding box `B` containing all of them.
+///
+/// The extent is an error if any two boxes have different dimensions, whatever
+/// the order in which they are merged.
+pub struct TExtent<B> {
+    extent: Option<Result<B, DimensionMismatch>>,
+}
+
+impl<B> Default for TExtent<B> {
+    fn default() -> Self {
+        Self { extent: None }
+    }
+}
+
+/// Merges two partial extents, keeping the first error.
+fn merge_extents<B: Extent>(
+    first: Result<B, DimensionMismatch>,
+    second: Result<B, DimensionMismatch>,
+) -> Result<B, DimensionMismatch> {
+    first?.extend(&second?).ok_or(DimensionMismatch)
+}
+
+impl<T> PartialAggregate<T> for TExtent<T::TBB>
+where
+    T: Temporal,
+    T::TBB: Extent,
+{
+    type Output = Result<T::TBB, DimensionMismatch>;
+
+    fn add(&mut self, value: &T) {
+        let bounding_box = Ok(value.bounding_box());
+        self.extent = Some(match self.extent.take() {
+            Some(extent) => merge_extents(extent, bounding_box),
+            None => bounding_box,
+        });
+    }
+
+    fn merge(self, other: Self) -> Self {
+        let extent = match (self.extent, other.extent) {
+            (Some(first), Some(second)) => Some(merge_extents(first, second)),
+            (first, second) => first.or(second),
+        };
+        Self { extent }
+    }
+
+    fn finish(self) -> Option<Self::Output> {
+        self.extent
+    }
+}
+
+#[cfg(test)]
+#[serial_test::serial]
+mod tests {
+    use super::*;
+    use crate::{meos_initialize_timezone, OrderedTemporal, TFloat, TGeomPoint};
+
+    #[test]
+    fn batch_operations() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let values: Vec<TFloat> = (0..48)
+            .map(|i| {
+                format!("[{i}@2020-01-01 00:{i:02}:00+00, 0@2020-01-01 01:{i:02}:00+00]")
+                    .parse()
+                    .unwrap()
+            })
+            .collect();
+        let maxima = par_map(&values, OrderedTemporal::max_value);
+        assert!((maxima[10] - 10.).abs() < f64::EPSILON);
+
+        let count = par_aggregate::<TCount, _>(&values).unwrap();
+        assert_eq!(count.max_value(), 48);
+        let extent = par_aggregate::<TExtent<_>, _>(&values).unwrap().unwrap();
+        assert_eq!(extent.xmax(), Some(47.));
+        assert!(par_aggregate::<TCount, TFloat>(&[]).is_none());
+
+        let points: Vec<TGeomPoint> = vec![
+            "[POINT(0 0)@2020-01-01, POINT(1 1)@2020-01-02]"
+                .parse()
+                .unwrap(),
+            "[POINT(5 5)@2020-01-01, POINT(6 6)@2020-01-02]"
+                .parse()
+                .unwrap(),
+        ];
+        let stbox: STBox = "STBOX X((0.5, 0.5), (2, 2))".parse().unwrap();
+        assert_eq!(par_filter_by_stbox(&points, &stbox).len(), 1);
+        let extent = par_aggregate::<TExtent<_>, TGeomPoint>(&points)
+            .unwrap()
+            .unwrap();
+        assert_eq!(extent.xmin(), Some(0.));
+        assert_eq!(extent.xmax(), Some(6.));
+
+        let mut points = points;
+        points.push(
+            "[POINT Z(2 2 2)@2020-01-01, POINT Z(3 3 3)@2020-01-02]"
+                .parse()
+                .unwrap(),
+        );
+        for _ in 0..10 {
+            assert_eq!(
+                par_aggregate::<TExtent<_>, TGeomPoint>(&points).map(Result::err),
+                Some(Some(DimensionMismatch))
+            );
+        }
+    }
+}
@@ -0,0 +1,245 @@
+//! `serde` support for temporal, span and box types.
+//!
+//! Binary formats (e.g. bincode) use the (extended, little endian) WKB
+//! representation of each value. Human readable formats (e.g. JSON) use the
+//! canonical `meos` text representation of the values made of integers, text
+//! and timestamps. The text representation rounds floating point numbers, so
+//! the values with floating point components (temporal floats and points,
+//! float spans and boxes) are written as hex-encoded WKB instead, which keeps
+//! them exact. Both the text and the hex-encoded WKB of any value are accepted
+//! when reading a human readable format.
+
+use std::{
+    ffi::{c_char, c_void, CStr},
+    fmt::{self, Write},
+    str::FromStr,
+};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    collections::base::{Span, SpanSet},
+    errors::ParseError,
+    try_from_inner, Box as MeosBox, DateSpan, DateSpanSet, FloatSpan, FloatSpanSet, IntSpan,
+    IntSpanSet, MeosEnum, STBox, TBool, TBox, TFloat, TGeogPoint, TGeomPoint, TInt, TText,
+    Temporal, TsTzSet, TsTzSpan, TsTzSpanSet, WKBVariant,
+};
+
+fn take_string(out_str: *mut c_char) -> String {
+    let result = unsafe { CStr::from_ptr(out_str) }
+        .to_str()
+        .unwrap()
+        .to_owned();
+    unsafe { libc::free(out_str.cast::<c_void>()) };
+    result
+}
+
+fn wkb_variant() -> WKBVariant {
+    WKBVariant::NDR | WKBVariant::Extended
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(2 * bytes.len());
+    for byte in bytes {
+        let _ = write!(hex, "{byte:02X}");
+    }
+    hex
+}
+
+/// Decodes `hex`, or returns `None` if it is not an hex-encoded byte string.
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.is_empty() {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// Visitor accepting either the text or the WKB representation of `T`.
+struct MeosVisitor<T> {
+    from_wkb: fn(&[u8]) -> Result<T, ParseError>,
+}
+
+impl<'de, T: FromStr> de::Visitor<'de> for MeosVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a meos value as text or WKB bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let value = match from_hex(v) {
+            Some(wkb) => (self.from_wkb)(&wkb).ok(),
+            None => v.parse().ok(),
+        };
+        value.ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        (self.from_wkb)(v).map_err(|_| E::invalid_value(de::Unexpected::Bytes(v), &self))
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        self.visit_bytes(&bytes)
+    }
+}
+
+macro_rules! impl_serde {
+    ($type:ty, temporal, $to_string:tt) => {
+        impl_serde!(@impl $type, <$type>::try_from_wkb, $to_string);
+    };
+    ($type:ty, $from_wkb:ident, $to_string:tt) => {
+        impl_serde!(
+            @impl
+            $type,
+            |wkb| try_from_inner(
+                || unsafe { meos_sys::$from_wkb(wkb.as_ptr(), wkb.len()) },
+                <$type>::from_inner
+            ),
+            $to_string
+        );
+    };
+    (@impl $type:ty, $from_wkb:expr, hex) => {
+        impl_serde!(
+            @impl $type,
+            $from_wkb,
+            (|value: &$type| to_hex(&value.as_wkb(wkb_variant())))
+        );
+    };
+    (@impl $type:ty, $from_wkb:expr, ($out:path)) => {
+        impl_serde!(
+            @impl $type,
+            $from_wkb,
+            (|value: &$type| take_string(unsafe { $out(value.inner()) }))
+        );
+    };
+    (@impl $type:ty, $from_wkb:expr, ($to_string:expr)) => {
+        impl Serialize for $type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    serializer.serialize_str(&($to_string)(self))
+                } else {
+                    serializer.serialize_bytes(&self.as_wkb(wkb_variant()))
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $type {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let visitor = MeosVisitor {
+                    from_wkb: $from_wkb,
+                };
+                if deserializer.is_human_readable() {
+                    deserializer.deserialize_str(visitor)
+                } else {
+                    deserializer.deserialize_bytes(visitor)
+                }
+            }
+        }
+    };
+}
+
+impl_serde!(TBool, temporal, (meos_sys::tbool_out));
+impl_serde!(TInt, temporal, (meos_sys::tint_out));
+impl_serde!(TFloat, temporal, hex);
+impl_serde!(TText, temporal, (meos_sys::ttext_out));
+impl_serde!(TGeomPoint, temporal, hex);
+impl_serde!(TGeogPoint, temporal, hex);
+
+impl_serde!(IntSpan, span_from_wkb, (meos_sys::intspan_out));
+impl_serde!(IntSpanSet, spanset_from_wkb, (meos_sys::intspanset_out));
+impl_serde!(FloatSpan, span_from_wkb, hex);
+impl_serde!(FloatSpanSet, spanset_from_wkb, hex);
+impl_serde!(DateSpan, span_from_wkb, (meos_sys::datespan_out));
+impl_serde!(DateSpanSet, spanset_from_wkb, (meos_sys::datespanset_out));
+impl_serde!(TsTzSet, set_from_wkb, (meos_sys::tstzset_out));
+impl_serde!(TsTzSpan, span_from_wkb, (meos_sys::tstzspan_out));
+impl_serde!(TsTzSpanSet, spanset_from_wkb, (meos_sys::tstzspanset_out));
+
+impl_serde!(TBox, tbox_from_wkb, hex);
+impl_serde!(STBox, stbox_from_wkb, hex);
+
+#[cfg(test)]
+#[serial_test::serial]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::{
+        meos_initialize, meos_initialize_timezone, FloatSpan, STBox, TFloat, TFloatInstant,
+        TInstant, TInt, Temporal, TsTzSet,
+    };
+
+    #[test]
+    fn json_round_trip() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let string = "[1@2020-01-01 00:00:00+00, 2@2020-01-02 00:00:00+00]";
+        let tint: TInt = string.parse().unwrap();
+        let json = serde_json::to_string(&tint).unwrap();
+        assert_eq!(json, format!("\"{string}\""));
+        assert_eq!(serde_json::from_str::<TInt>(&json).unwrap(), tint);
+
+        let string = "{2020-01-01 00:00:00+00, 2020-01-02 00:00:00+00}";
+        let set: TsTzSet = string.parse().unwrap();
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(json, format!("\"{string}\""));
+        assert_eq!(serde_json::from_str::<TsTzSet>(&json).unwrap(), set);
+        let bytes = bincode::serialize(&set).unwrap();
+        assert_eq!(bincode::deserialize::<TsTzSet>(&bytes).unwrap(), set);
+    }
+
+    #[test]
+    fn json_float_precision() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let value = 0.1 + 0.2;
+        let tfloat = TFloat::Instant(TFloatInstant::from_value_and_timestamp(
+            value,
+            Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+        ));
+        let json = serde_json::to_string(&tfloat).unwrap();
+        let read = serde_json::from_str::<TFloat>(&json).unwrap();
+        assert_eq!(read.start_value().to_bits(), value.to_bits());
+
+        let span: FloatSpan = "[1.5, 3)".parse().unwrap();
+        let json = serde_json::to_string(&span).unwrap();
+        assert_eq!(serde_json::from_str::<FloatSpan>(&json).unwrap(), span);
+
+        let string = "[1.5@2020-01-01 00:00:00+00, 2.25@2020-01-02 00:00:00+00]";
+        let tfloat: TFloat = string.parse().unwrap();
+        let json = format!("\"{string}\"");
+        assert_eq!(serde_json::from_str::<TFloat>(&json).unwrap(), tfloat);
+    }
+
+    #[test]
+    fn binary_round_trip() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let tint: TInt = "{[1@2020-01-01 00:00:00+00, 2@2020-01-02 00:00:00+00]}"
+            .parse()
+            .unwrap();
+        let bytes = bincode::serialize(&tint).unwrap();
+        assert_eq!(bincode::deserialize::<TInt>(&bytes).unwrap(), tint);
+
+        let stbox: STBox = "STBOX X((1, 2), (3, 4))".parse().unwrap();
+        let bytes = bincode::serialize(&stbox).unwrap();
+        assert_eq!(bincode::deserialize::<STBox>(&bytes).unwrap(), stbox);
+    }
+
+    #[test]
+    fn malformed_input() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        assert!(serde_json::from_str::<TFloat>("\"[1.5@not a date]\"").is_err());
+        assert!(serde_json::from_str::<FloatSpan>("\"(3, 1]\"").is_err());
+        let bytes = bincode::serialize(&[1_u8, 2, 3][..]).unwrap();
+        assert!(bincode::deserialize::<TFloat>(&bytes).is_err());
+        assert!(bincode::deserialize::<STBox>(&bytes).is_err());
+    }
+}
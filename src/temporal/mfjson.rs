@@ -0,0 +1,96 @@
+use super::JSONCVariant;
+
+#[cfg(feature = "serde")]
+mod collection;
+#[cfg(feature = "serde")]
+pub use collection::*;
+
+/// Output options of the MF-JSON representation of temporal values.
+///
+/// ## Example
+/// ```
+/// # use meos::{JSONCVariant, MfJsonOptions};
+/// let options = MfJsonOptions::new()
+///     .with_bbox(true)
+///     .precision(6)
+///     .srs("EPSG:4326")
+///     .variant(JSONCVariant::Pretty);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MfJsonOptions {
+    pub(crate) with_bbox: bool,
+    pub(crate) precision: i32,
+    pub(crate) srs: Option<String>,
+    pub(crate) variant: JSONCVariant,
+}
+
+impl Default for MfJsonOptions {
+    fn default() -> Self {
+        Self {
+            with_bbox: false,
+            precision: 15,
+            srs: None,
+            variant: JSONCVariant::Plain,
+        }
+    }
+}
+
+impl MfJsonOptions {
+    /// Creates the default options: no bounding box, 15 decimal digits, no SRS and plain output.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the bounding box is included in the output.
+    pub fn with_bbox(mut self, with_bbox: bool) -> Self {
+        self.with_bbox = with_bbox;
+        self
+    }
+
+    /// Sets the maximum number of decimal digits of the coordinates and values.
+    pub fn precision(mut self, precision: i32) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Sets the spatial reference system written in the `crs` member, e.g. `"EPSG:4326"`.
+    pub fn srs(mut self, srs: impl Into<String>) -> Self {
+        self.srs = Some(srs.into());
+        self
+    }
+
+    /// Sets the whitespace style of the output.
+    pub fn variant(mut self, variant: JSONCVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Shorthand for `variant(JSONCVariant::Pretty)`.
+    pub fn pretty(self) -> Self {
+        self.variant(JSONCVariant::Pretty)
+    }
+}
+
+#[cfg(test)]
+#[serial_test::serial]
+mod tests {
+    use super::*;
+    use crate::{meos_initialize, meos_initialize_timezone, MeosEnum, TGeomPoint};
+
+    #[test]
+    fn single_value() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let value: TGeomPoint =
+            "[POINT(1 1)@2020-01-01 00:00:00+00, POINT(2 2)@2020-01-01 00:01:00+00]"
+                .parse()
+                .unwrap();
+        let plain = value.as_mfjson(&MfJsonOptions::new());
+        assert!(!plain.contains('\n'));
+        assert!(!plain.contains("crs"));
+        let pretty = value.as_mfjson(&MfJsonOptions::new().srs("EPSG:4326").pretty());
+        assert!(pretty.contains('\n'));
+        assert!(pretty.contains("EPSG:4326"));
+        assert_eq!(TGeomPoint::from_mfjson(&pretty), value);
+    }
+}
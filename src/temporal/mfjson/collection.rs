@@ -0,0 +1,205 @@
+//! Reading and writing OGC MF-JSON `FeatureCollection`s of moving points.
+
+use std::collections::BTreeMap;
+
+use serde_json::{Map, Value};
+
+use crate::{errors::ParseError, JSONCVariant, MeosEnum, TFloat, TGeomPoint, TText};
+
+use super::MfJsonOptions;
+
+/// A moving feature of an OGC MF-JSON `FeatureCollection`: a temporal point
+/// trajectory together with temporal properties keyed by name.
+#[derive(Debug)]
+pub struct MfJsonFeature {
+    pub trajectory: TGeomPoint,
+    pub float_properties: BTreeMap<String, TFloat>,
+    pub text_properties: BTreeMap<String, TText>,
+}
+
+impl MfJsonFeature {
+    pub fn new(trajectory: TGeomPoint) -> Self {
+        Self {
+            trajectory,
+            float_properties: BTreeMap::new(),
+            text_properties: BTreeMap::new(),
+        }
+    }
+
+    /// Adds a temporal float property.
+    pub fn with_float_property(mut self, name: impl Into<String>, property: TFloat) -> Self {
+        self.float_properties.insert(name.into(), property);
+        self
+    }
+
+    /// Adds a temporal text property.
+    pub fn with_text_property(mut self, name: impl Into<String>, property: TText) -> Self {
+        self.text_properties.insert(name.into(), property);
+        self
+    }
+}
+
+/// Returns the MF-JSON representation of `temporal` written with `options`,
+/// parsed as a JSON value.
+fn to_value<T: MeosEnum>(temporal: &T, options: &MfJsonOptions) -> Value {
+    let mfjson = temporal.as_mfjson(options);
+    // The output of `meos` is always valid JSON
+    serde_json::from_str(&mfjson).expect("meos produced invalid JSON")
+}
+
+/// Returns the OGC `temporalProperties` group of a single property.
+///
+/// Instants and sequences are written with the OGC layout, where the
+/// `datetimes` are a member of the group. Sequence sets have no OGC
+/// equivalent and are written as their `meos` MF-JSON representation.
+fn property_group(name: &str, mut property: Value, ogc_type: &str) -> Value {
+    let mut group = Map::new();
+    if let Some(datetimes) = property.as_object_mut().and_then(|p| p.remove("datetimes")) {
+        group.insert("datetimes".to_owned(), datetimes);
+        property["type"] = Value::from(ogc_type);
+    }
+    group.insert(name.to_owned(), property);
+    Value::Object(group)
+}
+
+/// Writes `features` as an OGC MF-JSON `FeatureCollection`.
+///
+/// The spatial reference system of `options` is only applied to the trajectories.
+pub fn write_mfjson_feature_collection(
+    features: &[MfJsonFeature],
+    options: &MfJsonOptions,
+) -> String {
+    let property_options = MfJsonOptions {
+        srs: None,
+        ..options.clone()
+    };
+    let features: Vec<Value> = features
+        .iter()
+        .map(|feature| {
+            let groups: Vec<Value> = feature
+                .float_properties
+                .iter()
+                .map(|(name, property)| {
+                    property_group(name, to_value(property, &property_options), "Measure")
+                })
+                .chain(feature.text_properties.iter().map(|(name, property)| {
+                    property_group(name, to_value(property, &property_options), "Text")
+                }))
+                .collect();
+
+            let mut object = Map::new();
+            object.insert("type".to_owned(), Value::from("Feature"));
+            object.insert(
+                "temporalGeometry".to_owned(),
+                to_value(&feature.trajectory, options),
+            );
+            if !groups.is_empty() {
+                object.insert("temporalProperties".to_owned(), Value::Array(groups));
+            }
+            Value::Object(object)
+        })
+        .collect();
+
+    let collection = serde_json::json!({
+        "type": "FeatureCollection",
+        "features": features,
+    });
+    match options.variant {
+        JSONCVariant::Pretty => serde_json::to_string_pretty(&collection),
+        JSONCVariant::Plain | JSONCVariant::Spaced => serde_json::to_string(&collection),
+    }
+    .expect("Serializing JSON values can't fail")
+}
+
+/// Reads an OGC MF-JSON `FeatureCollection` of moving points.
+///
+/// Temporal properties can either use the OGC layout (a `Measure` or `Text`
+/// property sharing the `datetimes` of its group) or the `meos` one
+/// (`MovingFloat` or `MovingText` objects).
+pub fn read_mfjson_feature_collection(mfjson: &str) -> Result<Vec<MfJsonFeature>, ParseError> {
+    let collection: Value = serde_json::from_str(mfjson).map_err(|_| ParseError)?;
+    if collection["type"] != "FeatureCollection" {
+        return Err(ParseError);
+    }
+    collection["features"]
+        .as_array()
+        .ok_or(ParseError)?
+        .iter()
+        .map(read_feature)
+        .collect()
+}
+
+fn read_feature(feature: &Value) -> Result<MfJsonFeature, ParseError> {
+    let geometry = feature.get("temporalGeometry").ok_or(ParseError)?;
+    let mut result = MfJsonFeature::new(TGeomPoint::try_from_mfjson(&geometry.to_string())?);
+
+    let groups = match feature.get("temporalProperties") {
+        Some(groups) => groups.as_array().ok_or(ParseError)?.as_slice(),
+        None => &[],
+    };
+    for group in groups {
+        let group = group.as_object().ok_or(ParseError)?;
+        let datetimes = group.get("datetimes");
+        for (name, property) in group.iter().filter(|(name, _)| *name != "datetimes") {
+            let mut property = property.clone();
+            let object = property.as_object_mut().ok_or(ParseError)?;
+            if let Some(datetimes) = datetimes {
+                object
+                    .entry("datetimes")
+                    .or_insert_with(|| datetimes.clone());
+            }
+            match object.get("type").and_then(Value::as_str) {
+                Some("Measure" | "MovingFloat") => {
+                    object.insert("type".to_owned(), Value::from("MovingFloat"));
+                    let property = TFloat::try_from_mfjson(&property.to_string())?;
+                    result.float_properties.insert(name.clone(), property);
+                }
+                Some("Text" | "MovingText") => {
+                    object.insert("type".to_owned(), Value::from("MovingText"));
+                    let property = TText::try_from_mfjson(&property.to_string())?;
+                    result.text_properties.insert(name.clone(), property);
+                }
+                _ => return Err(ParseError),
+            }
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+#[serial_test::serial]
+mod tests {
+    use super::*;
+    use crate::{meos_initialize, meos_initialize_timezone};
+
+    #[test]
+    fn feature_collection_round_trip() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let trajectory: TGeomPoint =
+            "[POINT(1 1)@2020-01-01 00:00:00+00, POINT(2 2)@2020-01-01 00:01:00+00]"
+                .parse()
+                .unwrap();
+        let speed: TFloat = "[1.5@2020-01-01 00:00:00+00, 2.5@2020-01-01 00:01:00+00]"
+            .parse()
+            .unwrap();
+        let name: TText = "{\"a\"@2020-01-01 00:00:00+00, \"b\"@2020-01-01 00:01:00+00}"
+            .parse()
+            .unwrap();
+        let features = [MfJsonFeature::new(trajectory)
+            .with_float_property("speed", speed)
+            .with_text_property("name", name)];
+
+        let mfjson = write_mfjson_feature_collection(&features, &MfJsonOptions::new());
+        let read = read_mfjson_feature_collection(&mfjson).unwrap();
+        assert_eq!(read.len(), 1);
+        assert_eq!(read[0].trajectory, features[0].trajectory);
+        assert_eq!(read[0].float_properties, features[0].float_properties);
+        assert_eq!(read[0].text_properties, features[0].text_properties);
+
+        assert_eq!(
+            TFloat::try_from_mfjson("{\"type\": \"Nope\"}"),
+            Err(ParseError)
+        );
+    }
+}
@@ -1,29 +1,37 @@
 mod interpolation;
 pub use interpolation::TInterpolation;
 
+mod mfjson;
+pub use mfjson::*;
+
 mod number;
 pub use number::tfloat::*;
 pub use number::tint::*;
 pub use number::tnumber::TNumber;
 
-#[cfg(feature = "geos")]
 mod point;
+#[cfg(feature = "serde")]
+pub use point::geojson::*;
+#[cfg(feature = "gpx")]
+pub use point::gpx;
+pub use point::gserialized::GSerialized;
 pub use point::tgeogpoint::*;
 pub use point::tgeompoint::*;
 pub use point::tpoint::*;
+pub use point::trip_assembler::TripAssembler;
 
 mod tbool;
 pub use tbool::*;
 
 #[allow(clippy::module_inception)]
 mod temporal;
-pub use temporal::{OrderedTemporal, SimplifiableTemporal, Temporal};
+pub use temporal::{GapStatistics, OrderedTemporal, Sequences, SimplifiableTemporal, Temporal};
 
 mod tinstant;
 pub use tinstant::TInstant;
 
 mod tsequence;
-pub use tsequence::TSequence;
+pub use tsequence::{TSequence, TSequenceBuilder, TSequenceBuilderError};
 
 mod tsequence_set;
 pub use tsequence_set::TSequenceSet;
@@ -32,6 +40,7 @@ mod ttext;
 pub use ttext::*;
 
 /// Taken from <https://json-c.github.io/json-c/json-c-0.10/doc/html/json__object_8h.html#a3294cb92765cdeb497cfd346644d1059>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum JSONCVariant {
     Plain,
     Spaced,
@@ -8,7 +8,8 @@ use std::{
 
 use chrono::{DateTime, TimeZone};
 
-use super::tnumber::{impl_meos_enum, impl_temporal_for_tnumber, TNumber};
+use super::tint::{TInt, TIntInstant, TIntSequence, TIntSequenceSet};
+use super::tnumber::{impl_meos_enum, impl_temporal_for_tnumber, impl_tnumber_ops, TNumber};
 use crate::{
     boxes::TBox,
     collections::{
@@ -22,13 +23,15 @@ use crate::{
         interpolation::TInterpolation,
         tbool::{TBool, TBoolInstant, TBoolSequence, TBoolSequenceSet},
         temporal::{
-            impl_always_and_ever_value_equality_functions, impl_ordered_temporal_functions,
-            impl_simple_traits_for_temporal, OrderedTemporal, SimplifiableTemporal, Temporal,
+            impl_always_and_ever_value_equality_functions, impl_from_base_temporal,
+            impl_ordered_temporal_functions, impl_simple_traits_for_temporal,
+            impl_temporal_conversion, OrderedTemporal, SimplifiableTemporal, Temporal,
         },
         tinstant::TInstant,
         tsequence::TSequence,
         tsequence_set::TSequenceSet,
     },
+    try_factory,
     utils::to_meos_timestamp,
     MeosEnum,
 };
@@ -57,6 +60,8 @@ pub enum TFloat {
 
 impl_from_str!(TFloat);
 
+impl_from_base_temporal!(TFloat, f64, "tfloat_from_base_temp");
+
 impl MeosEnum for TFloat {
     fn from_instant(inner: *mut meos_sys::TInstant) -> Self {
         Self::Instant(TFloatInstant {
@@ -76,9 +81,9 @@ impl MeosEnum for TFloat {
         })
     }
 
-    fn from_mfjson(mfjson: &str) -> Self {
-        let cstr = CString::new(mfjson).unwrap();
-        factory::<Self>(unsafe { meos_sys::tfloat_from_mfjson(cstr.as_ptr()) })
+    fn try_from_mfjson(mfjson: &str) -> Result<Self, ParseError> {
+        let cstr = CString::new(mfjson).map_err(|_| ParseError)?;
+        try_factory::<Self>(|| unsafe { meos_sys::tfloat_from_mfjson(cstr.as_ptr()) })
     }
 }
 
@@ -102,6 +107,76 @@ pub trait TFloatTrait:
         let modified = unsafe { meos_sys::tfloat_shift_scale_value(self.inner(), d, w) };
         Self::from_inner_as_temporal(modified)
     }
+
+    // ------------------------- Mathematical Functions ------------------------
+
+    /// Returns a new `TFloat` with the exponential of the value(s) of `self`.
+    #[doc(alias = "tfloat_exp")]
+    fn exp(&self) -> Self {
+        Self::from_inner_as_temporal(unsafe { meos_sys::tfloat_exp(self.inner()) })
+    }
+
+    /// Returns a new `TFloat` with the natural logarithm of the value(s) of `self`.
+    #[doc(alias = "tfloat_ln")]
+    fn ln(&self) -> Self {
+        Self::from_inner_as_temporal(unsafe { meos_sys::tfloat_ln(self.inner()) })
+    }
+
+    /// Returns a new `TFloat` with the base 10 logarithm of the value(s) of `self`.
+    #[doc(alias = "tfloat_log10")]
+    fn log10(&self) -> Self {
+        Self::from_inner_as_temporal(unsafe { meos_sys::tfloat_log10(self.inner()) })
+    }
+
+    /// Returns a new `TFloat` with the value(s) of `self` rounded down to the nearest integer.
+    #[doc(alias = "tfloat_floor")]
+    fn floor(&self) -> Self {
+        Self::from_inner_as_temporal(unsafe { meos_sys::tfloat_floor(self.inner()) })
+    }
+
+    /// Returns a new `TFloat` with the value(s) of `self` rounded up to the nearest integer.
+    #[doc(alias = "tfloat_ceil")]
+    fn ceil(&self) -> Self {
+        Self::from_inner_as_temporal(unsafe { meos_sys::tfloat_ceil(self.inner()) })
+    }
+
+    /// Returns a new `TFloat` with the value(s) of `self` converted from radians to degrees.
+    ///
+    /// # Arguments
+    /// * `normalize` - Whether the result is normalized to the range [0, 360).
+    #[doc(alias = "tfloat_degrees")]
+    fn degrees(&self, normalize: bool) -> Self {
+        Self::from_inner_as_temporal(unsafe { meos_sys::tfloat_degrees(self.inner(), normalize) })
+    }
+
+    /// Returns a new `TFloat` with the value(s) of `self` converted from degrees to radians.
+    #[doc(alias = "tfloat_radians")]
+    fn radians(&self) -> Self {
+        Self::from_inner_as_temporal(unsafe { meos_sys::tfloat_radians(self.inner()) })
+    }
+
+    /// Returns the rate of change of `self` per second, as a stepwise `TFloat`.
+    ///
+    /// Only linear values have a derivative. In particular, the
+    /// [`speed`](crate::TPointTrait::speed) of a temporal point is stepwise, so
+    /// its derivative is always `None`: use
+    /// [`acceleration`](crate::TPointTrait::acceleration) instead.
+    ///
+    /// ## Returns
+    /// The derivative, or `None` if `self` does not have linear interpolation
+    /// or has a single instant.
+    #[doc(alias = "temporal_derivative")]
+    fn derivative(&self) -> Option<TFloat> {
+        if self.interpolation() != TInterpolation::Linear {
+            return None;
+        }
+        let result = unsafe { meos_sys::temporal_derivative(self.inner()) };
+        if result.is_null() {
+            None
+        } else {
+            Some(factory::<TFloat>(result))
+        }
+    }
 }
 
 pub struct TFloatInstant {
@@ -120,6 +195,7 @@ impl TInstant for TFloatInstant {
     }
 
     fn from_value_and_timestamp<Tz: TimeZone>(value: Self::Type, timestamp: DateTime<Tz>) -> Self {
+        crate::meos_initialize();
         Self::from_inner(unsafe { meos_sys::tfloatinst_make(value, to_meos_timestamp(&timestamp)) })
     }
 }
@@ -283,4 +359,50 @@ impl TryFrom<TFloat> for TFloatSequenceSet {
         }
     }
 }
+
+impl_temporal_conversion!(From, TInt => TFloat, meos_sys::tint_to_tfloat);
+
 impl_meos_enum!(TFloat, f64, Float);
+
+impl TFloatTrait for TFloat {}
+
+#[cfg(test)]
+#[serial_test::serial]
+mod tests {
+    use super::*;
+    use crate::{meos_initialize, meos_initialize_timezone, FloatSpan};
+
+    #[test]
+    fn arithmetic_operators() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let speed: TFloat = "[10@2018-01-01 08:00:00+00, 20@2018-01-01 08:10:00+00]"
+            .parse()
+            .unwrap();
+        let expected: TFloat = "[36@2018-01-01 08:00:00+00, 72@2018-01-01 08:10:00+00]"
+            .parse()
+            .unwrap();
+        assert_eq!(&speed * 3.6, expected);
+        let other: TFloat = "[1@2018-01-01 09:00:00+00, 2@2018-01-01 09:10:00+00]"
+            .parse()
+            .unwrap();
+        assert!((&speed + &other).is_none());
+        assert!((&speed - &speed).unwrap().max_value().abs() < f64::EPSILON);
+        assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| &speed / 0.0)).is_err());
+    }
+
+    #[test]
+    fn math_functions() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let heading: TFloat = "[0@2018-01-01 08:00:00+00, 3.14159@2018-01-01 08:10:00+00]"
+            .parse()
+            .unwrap();
+        let expected: TFloat = "[0@2018-01-01 08:00:00+00, 179@2018-01-01 08:10:00+00]"
+            .parse()
+            .unwrap();
+        assert_eq!(heading.degrees(false).floor(), expected);
+        let span: FloatSpan = (0.5..2.5).into();
+        assert_eq!(span.floor(), (0.0..2.0).into());
+    }
+}
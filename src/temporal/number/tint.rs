@@ -21,18 +21,21 @@ use crate::{
         interpolation::TInterpolation,
         tbool::{TBool, TBoolInstant, TBoolSequence, TBoolSequenceSet},
         temporal::{
-            impl_always_and_ever_value_equality_functions, impl_ordered_temporal_functions,
-            impl_simple_traits_for_temporal, OrderedTemporal, Temporal,
+            impl_always_and_ever_value_equality_functions, impl_from_base_temporal,
+            impl_ordered_temporal_functions, impl_simple_traits_for_temporal,
+            impl_temporal_conversion, OrderedTemporal, Temporal,
         },
         tinstant::TInstant,
         tsequence::TSequence,
         tsequence_set::TSequenceSet,
     },
+    try_factory,
     utils::to_meos_timestamp,
     MeosEnum,
 };
 
-use super::tnumber::{impl_meos_enum, impl_temporal_for_tnumber, TNumber};
+use super::tfloat::{TFloat, TFloatInstant, TFloatSequence, TFloatSequenceSet};
+use super::tnumber::{impl_meos_enum, impl_temporal_for_tnumber, impl_tnumber_ops, TNumber};
 
 #[derive(Debug)]
 pub enum TInt {
@@ -43,6 +46,8 @@ pub enum TInt {
 
 impl_from_str!(TInt);
 
+impl_from_base_temporal!(TInt, i32, "tint_from_base_temp");
+
 impl MeosEnum for TInt {
     fn from_instant(inner: *mut meos_sys::TInstant) -> Self {
         Self::Instant(TIntInstant::from_inner(inner))
@@ -56,9 +61,9 @@ impl MeosEnum for TInt {
         Self::SequenceSet(TIntSequenceSet::from_inner(inner))
     }
 
-    fn from_mfjson(mfjson: &str) -> Self {
-        let cstr = CString::new(mfjson).unwrap();
-        factory::<Self>(unsafe { meos_sys::tint_from_mfjson(cstr.as_ptr()) })
+    fn try_from_mfjson(mfjson: &str) -> Result<Self, ParseError> {
+        let cstr = CString::new(mfjson).map_err(|_| ParseError)?;
+        try_factory::<Self>(|| unsafe { meos_sys::tint_from_mfjson(cstr.as_ptr()) })
     }
 }
 
@@ -122,6 +127,7 @@ impl TInstant for TIntInstant {
     }
 
     fn from_value_and_timestamp<Tz: TimeZone>(value: Self::Type, timestamp: DateTime<Tz>) -> Self {
+        crate::meos_initialize();
         Self::from_inner(unsafe { meos_sys::tintinst_make(value, to_meos_timestamp(&timestamp)) })
     }
 }
@@ -325,4 +331,52 @@ impl TryFrom<TInt> for TIntSequenceSet {
     }
 }
 
+impl_temporal_conversion!(From, TBool => TInt, meos_sys::tbool_to_tint);
+impl_temporal_conversion!(TryFrom, TFloat => TInt, meos_sys::tfloat_to_tint);
+
 impl_meos_enum!(TInt, i32, Int);
+
+#[cfg(test)]
+#[serial_test::serial]
+mod tests {
+    use super::*;
+    use crate::{meos_initialize, meos_initialize_timezone};
+
+    #[test]
+    fn arithmetic_operators() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let count: TInt = "{1@2018-01-01 08:00:00+00, 2@2018-01-01 08:10:00+00}"
+            .parse()
+            .unwrap();
+        let expected: TInt = "{101@2018-01-01 08:00:00+00, 102@2018-01-01 08:10:00+00}"
+            .parse()
+            .unwrap();
+        assert_eq!(100 - -count, expected);
+        let zero: TInt = "0@2018-01-01 08:00:00+00".parse().unwrap();
+        assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| 1 / &zero)).is_err());
+    }
+
+    #[test]
+    fn conversions() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let value: TBoolSequence = "[t@2018-01-01 08:00:00+00, f@2018-01-01 08:10:00+00]"
+            .parse::<TBool>()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let value = TIntSequence::from(&value);
+        let expected: TInt = "[1@2018-01-01 08:00:00+00, 0@2018-01-01 08:10:00+00]"
+            .parse()
+            .unwrap();
+        assert_eq!(TInt::Sequence(value.clone()), expected);
+        let value = TFloatSequence::from(value);
+        assert_eq!(value.interpolation(), TInterpolation::Stepwise);
+        assert!(TIntSequence::try_from(&value).is_ok());
+        let linear: TFloat = "[1.5@2018-01-01 08:00:00+00, 2@2018-01-01 08:10:00+00]"
+            .parse()
+            .unwrap();
+        assert!(TInt::try_from(linear).is_err());
+    }
+}
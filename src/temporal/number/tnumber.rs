@@ -1,7 +1,10 @@
+use std::ptr;
+
 use crate::{
     boxes::TBox,
     collections::number::{NumberSpan, NumberSpanSet},
     temporal::temporal::Temporal,
+    utils::from_meos_array,
 };
 
 pub trait TNumber: Temporal<TBB = TBox> {
@@ -39,6 +42,43 @@ pub trait TNumber: Temporal<TBB = TBox> {
         unsafe { meos_sys::tnumber_twavg(self.inner()) }
     }
 
+    /// Returns up to `box_count` boxes covering `self`, each spanning a
+    /// similar number of instants.
+    ///
+    /// ## Arguments
+    /// * `box_count` - Maximum number of boxes.
+    #[doc(alias = "tnumber_split_n_tboxes")]
+    fn split_n_tboxes(&self, box_count: i32) -> Vec<TBox> {
+        let mut count = 0;
+        unsafe {
+            let boxes =
+                meos_sys::tnumber_split_n_tboxes(self.inner(), box_count, ptr::addr_of_mut!(count));
+            from_meos_array(boxes, count, |tbox| {
+                TBox::from_inner(meos_sys::tbox_copy(tbox))
+            })
+        }
+    }
+
+    /// Returns the boxes covering `self`, each spanning `elem_count`
+    /// consecutive instants.
+    ///
+    /// ## Arguments
+    /// * `elem_count` - Number of instants per box.
+    #[doc(alias = "tnumber_split_each_n_tboxes")]
+    fn split_each_n_tboxes(&self, elem_count: i32) -> Vec<TBox> {
+        let mut count = 0;
+        unsafe {
+            let boxes = meos_sys::tnumber_split_each_n_tboxes(
+                self.inner(),
+                elem_count,
+                ptr::addr_of_mut!(count),
+            );
+            from_meos_array(boxes, count, |tbox| {
+                TBox::from_inner(meos_sys::tbox_copy(tbox))
+            })
+        }
+    }
+
     // ------------------------- Restrictions ----------------------------------
     /// Returns a new temporal object with the values of `self` where it's not in `span`
     ///
@@ -154,7 +194,7 @@ macro_rules! impl_temporal_for_tnumber {
                 }
             }
             impl_simple_traits_for_temporal!($type, with_drop);
-
+            impl_tnumber_ops!($type, $base_type, $basic_type);
 
             impl TNumber for $type {
                 fn nearest_approach_distance(&self, other: &Self) -> Self::Type {
@@ -297,6 +337,7 @@ macro_rules! impl_meos_enum {
             }
         }
         impl_simple_traits_for_temporal!($type);
+        impl_tnumber_ops!($type, $base_type, $basic_type);
 
 
         impl TNumber for $type {
@@ -429,5 +470,100 @@ macro_rules! impl_meos_enum {
     };
 }
 
+/// Implements the arithmetic operators of `std::ops` for a temporal number.
+///
+/// Operations with a scalar keep the subtype of the temporal number. Operations
+/// between two temporal numbers are computed on their common time, and return
+/// `None` if there is none. Dividing by zero, or by a temporal number that is
+/// zero at some instant, panics with the error reported by `meos`.
+macro_rules! impl_tnumber_ops {
+    ($type:ty, $base_type:ty, $basic_type:ident) => {
+        impl_tnumber_ops!(@op $type, $base_type, $basic_type, Add, add, add);
+        impl_tnumber_ops!(@op $type, $base_type, $basic_type, Sub, sub, sub);
+        impl_tnumber_ops!(@op $type, $base_type, $basic_type, Mul, mul, mult);
+        impl_tnumber_ops!(@op $type, $base_type, $basic_type, Div, div, div);
+
+        impl std::ops::Neg for &$type {
+            type Output = $type;
+
+            fn neg(self) -> $type {
+                self * <$base_type>::from(-1_i8)
+            }
+        }
+
+        impl std::ops::Neg for $type {
+            type Output = $type;
+
+            fn neg(self) -> $type {
+                -&self
+            }
+        }
+    };
+    (@op $type:ty, $base_type:ty, $basic_type:ident, $trait:ident, $method:ident, $meos:ident) => {
+        paste::paste! {
+            impl std::ops::$trait<$base_type> for &$type {
+                type Output = $type;
+
+                fn $method(self, rhs: $base_type) -> $type {
+                    <$type>::from_inner_as_temporal(impl_tnumber_ops!(@checked unsafe {
+                        meos_sys::[<$meos _t $basic_type:lower _ $basic_type:lower>](self.inner(), rhs)
+                    }))
+                }
+            }
+
+            impl std::ops::$trait<$base_type> for $type {
+                type Output = $type;
+
+                fn $method(self, rhs: $base_type) -> $type {
+                    std::ops::$trait::$method(&self, rhs)
+                }
+            }
+
+            impl std::ops::$trait<&$type> for $base_type {
+                type Output = $type;
+
+                fn $method(self, rhs: &$type) -> $type {
+                    <$type>::from_inner_as_temporal(impl_tnumber_ops!(@checked unsafe {
+                        meos_sys::[<$meos _ $basic_type:lower _t $basic_type:lower>](self, rhs.inner())
+                    }))
+                }
+            }
+
+            impl std::ops::$trait<$type> for $base_type {
+                type Output = $type;
+
+                fn $method(self, rhs: $type) -> $type {
+                    std::ops::$trait::$method(self, &rhs)
+                }
+            }
+
+            impl<T: TNumber<Type = $base_type>> std::ops::$trait<&T> for &$type {
+                type Output = Option<[<T $basic_type>]>;
+
+                fn $method(self, rhs: &T) -> Self::Output {
+                    let result = impl_tnumber_ops!(@checked unsafe {
+                        meos_sys::[<$meos _tnumber_tnumber>](self.inner(), rhs.inner())
+                    });
+                    (!result.is_null()).then(|| factory::<[<T $basic_type>]>(result))
+                }
+            }
+
+            impl<T: TNumber<Type = $base_type>> std::ops::$trait<&T> for $type {
+                type Output = Option<[<T $basic_type>]>;
+
+                fn $method(self, rhs: &T) -> Self::Output {
+                    std::ops::$trait::$method(&self, rhs)
+                }
+            }
+        }
+    };
+    // Panics in Rust on `meos` errors, such as a division by zero, which could
+    // not unwind through the `meos` error handler.
+    (@checked $call:expr) => {
+        $crate::catch_meos_error(|| $call).unwrap_or_else(|message| panic!("{message}"))
+    };
+}
+
 pub(crate) use impl_meos_enum;
 pub(crate) use impl_temporal_for_tnumber;
+pub(crate) use impl_tnumber_ops;
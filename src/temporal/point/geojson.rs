@@ -0,0 +1,286 @@
+//! Writing trips as `GeoJSON` for deck.gl and kepler.gl.
+//!
+//! This module is built on `serde_json`, so it is only available with the
+//! `serde` feature, which is the feature that enables `serde_json`.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Utc};
+use serde_json::{json, Map, Value};
+
+use crate::{TFloat, TGeomPoint, TGeomPointInstant, TInstant, Temporal};
+
+use super::tpoint::Point;
+
+/// Unit of the timestamps written by [`write_geojson_trips`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GeoJsonTimeUnit {
+    /// Seconds since the Unix epoch, as expected by kepler.gl.
+    #[default]
+    Seconds,
+    /// Milliseconds since the Unix epoch, as usually used with deck.gl.
+    Milliseconds,
+}
+
+/// Output options of [`write_geojson_trips`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GeoJsonTripsOptions {
+    pub(crate) time_unit: GeoJsonTimeUnit,
+    pub(crate) timestamps_in_coordinates: bool,
+}
+
+impl GeoJsonTripsOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the unit of the written timestamps.
+    pub fn time_unit(mut self, time_unit: GeoJsonTimeUnit) -> Self {
+        self.time_unit = time_unit;
+        self
+    }
+
+    /// Sets whether the timestamp of each vertex is also written as its 4th
+    /// coordinate (`[x, y, z, t]`, with `z = 0` for 2D points), which is the
+    /// layout of the kepler.gl trip layer.
+    pub fn timestamps_in_coordinates(mut self, timestamps_in_coordinates: bool) -> Self {
+        self.timestamps_in_coordinates = timestamps_in_coordinates;
+        self
+    }
+}
+
+/// A trajectory to be written by [`write_geojson_trips`], together with the
+/// temporal floats (e.g. its speed) sampled at each of its vertices.
+#[derive(Debug)]
+pub struct GeoJsonTrip {
+    pub id: Option<String>,
+    pub trajectory: TGeomPoint,
+    pub properties: BTreeMap<String, TFloat>,
+}
+
+impl GeoJsonTrip {
+    pub fn new(trajectory: TGeomPoint) -> Self {
+        Self {
+            id: None,
+            trajectory,
+            properties: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the id of the feature.
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Adds a temporal float, written as an array with its value at each vertex.
+    pub fn with_property(mut self, name: impl Into<String>, property: TFloat) -> Self {
+        self.properties.insert(name.into(), property);
+        self
+    }
+}
+
+/// Writes `trips` as a `GeoJSON` `FeatureCollection` of trajectories with timestamps.
+///
+/// Each sequence of a trip is a `LineString` feature with one vertex per
+/// instant, so that the gaps between the sequences of a trip are not drawn.
+/// Sequences with a single instant are written as a `Point` feature, since a
+/// `LineString` needs two positions. The properties of a feature contain a
+/// `times` array with the timestamp of each vertex and, for each temporal
+/// float of the trip, an array with the value at each vertex (`null` where it
+/// is not defined). The value at a vertex is the one of the instant of the
+/// temporal float at that timestamp if any, so exclusive bounds do not hide
+/// it. This is the layout used by the deck.gl `TripsLayer` and the kepler.gl
+/// trip layer.
+///
+/// The features of the sequences of a trip share its id. Trips with discrete
+/// interpolation are written as a single feature.
+///
+/// Only available with the `serde` feature.
+pub fn write_geojson_trips(trips: &[GeoJsonTrip], options: &GeoJsonTripsOptions) -> String {
+    let features: Vec<Value> = trips
+        .iter()
+        .flat_map(|trip| {
+            let sequences = trip.trajectory.sequences();
+            let parts: Vec<Vec<TGeomPointInstant>> = if sequences.is_empty() {
+                vec![trip.trajectory.instants()]
+            } else {
+                sequences.iter().map(Temporal::instants).collect()
+            };
+            let instant_values: Vec<HashMap<DateTime<Utc>, f64>> = trip
+                .properties
+                .values()
+                .map(|property| {
+                    property
+                        .instants()
+                        .iter()
+                        .map(|instant| (instant.timestamp(), instant.value()))
+                        .collect()
+                })
+                .collect();
+            parts
+                .into_iter()
+                .map(|instants| write_feature(trip, &instant_values, &instants, options))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    json!({ "type": "FeatureCollection", "features": features }).to_string()
+}
+
+/// Writes the feature of `trip` with the vertices `instants`, where
+/// `instant_values` are the values of the instants of each property of `trip`.
+fn write_feature(
+    trip: &GeoJsonTrip,
+    instant_values: &[HashMap<DateTime<Utc>, f64>],
+    instants: &[TGeomPointInstant],
+    options: &GeoJsonTripsOptions,
+) -> Value {
+    let timestamps: Vec<_> = instants.iter().map(TInstant::timestamp).collect();
+    let times: Vec<f64> = timestamps
+        .iter()
+        .map(|t| {
+            #[allow(clippy::cast_precision_loss)]
+            let micros = t.timestamp_micros() as f64;
+            match options.time_unit {
+                GeoJsonTimeUnit::Seconds => micros / 1e6,
+                GeoJsonTimeUnit::Milliseconds => micros / 1e3,
+            }
+        })
+        .collect();
+    let coordinates: Vec<Value> = instants
+        .iter()
+        .zip(&times)
+        .map(|(instant, &time)| {
+            let Point(x, y, z) = instant.value();
+            match (z, options.timestamps_in_coordinates) {
+                (Some(z), true) => json!([x, y, z, time]),
+                (None, true) => json!([x, y, 0.0, time]),
+                (Some(z), false) => json!([x, y, z]),
+                (None, false) => json!([x, y]),
+            }
+        })
+        .collect();
+
+    let mut properties = Map::new();
+    properties.insert("times".to_owned(), json!(times));
+    for ((name, property), instant_values) in trip.properties.iter().zip(instant_values) {
+        let values: Vec<Option<f64>> = timestamps
+            .iter()
+            .map(|t| {
+                instant_values
+                    .get(t)
+                    .copied()
+                    .or_else(|| property.value_at_timestamp(*t))
+            })
+            .collect();
+        properties.insert(name.clone(), json!(values));
+    }
+
+    let mut feature = Map::new();
+    feature.insert("type".to_owned(), json!("Feature"));
+    if let Some(id) = &trip.id {
+        feature.insert("id".to_owned(), json!(id));
+    }
+    let geometry = match coordinates.as_slice() {
+        [coordinates] => json!({ "type": "Point", "coordinates": coordinates }),
+        _ => json!({ "type": "LineString", "coordinates": coordinates }),
+    };
+    feature.insert("geometry".to_owned(), geometry);
+    feature.insert("properties".to_owned(), Value::Object(properties));
+    Value::Object(feature)
+}
+
+#[cfg(test)]
+#[serial_test::serial]
+mod tests {
+    use super::*;
+    use crate::{meos_initialize, meos_initialize_timezone, TPointTrait};
+
+    #[test]
+    fn trips_feature_collection() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let trajectory: TGeomPoint =
+            "[POINT(0 0)@2020-01-01 00:00:00+00, POINT(3 4)@2020-01-01 00:00:05+00]"
+                .parse()
+                .unwrap();
+        let speed = trajectory.speed();
+        let trips = [GeoJsonTrip::new(trajectory)
+            .with_id("a")
+            .with_property("speed", speed)];
+
+        let geojson: Value =
+            serde_json::from_str(&write_geojson_trips(&trips, &GeoJsonTripsOptions::new()))
+                .unwrap();
+        let feature = &geojson["features"][0];
+        assert_eq!(feature["id"], "a");
+        assert_eq!(
+            feature["geometry"]["coordinates"],
+            json!([[0.0, 0.0], [3.0, 4.0]])
+        );
+        assert_eq!(
+            feature["properties"]["times"],
+            json!([1_577_836_800.0, 1_577_836_805.0])
+        );
+        assert_eq!(feature["properties"]["speed"], json!([1.0, 1.0]));
+    }
+
+    #[test]
+    fn trips_with_gaps() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let trajectory: TGeomPoint = "{[POINT(0 0)@2020-01-01 00:00:00+00, \
+                                      POINT(1 1)@2020-01-01 00:00:05+00], \
+                                      [POINT(5 5)@2020-01-01 01:00:00+00, \
+                                      POINT(6 6)@2020-01-01 01:00:05+00]}"
+            .parse()
+            .unwrap();
+        let trips = [GeoJsonTrip::new(trajectory).with_id("a")];
+
+        let geojson: Value =
+            serde_json::from_str(&write_geojson_trips(&trips, &GeoJsonTripsOptions::new()))
+                .unwrap();
+        let features = geojson["features"].as_array().unwrap();
+        assert_eq!(features.len(), 2);
+        assert_eq!(features[1]["id"], "a");
+        assert_eq!(
+            features[0]["geometry"]["coordinates"],
+            json!([[0.0, 0.0], [1.0, 1.0]])
+        );
+        assert_eq!(
+            features[1]["geometry"]["coordinates"],
+            json!([[5.0, 5.0], [6.0, 6.0]])
+        );
+        assert_eq!(
+            features[1]["properties"]["times"],
+            json!([1_577_840_400.0, 1_577_840_405.0])
+        );
+    }
+
+    #[test]
+    fn single_instant_trips_and_exclusive_bounds() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let trajectory: TGeomPoint = "{[POINT(0 0)@2020-01-01 00:00:00+00, \
+                                      POINT(1 1)@2020-01-01 00:00:05+00], \
+                                      [POINT(5 5)@2020-01-01 01:00:00+00]}"
+            .parse()
+            .unwrap();
+        let heading: TFloat = "[1@2020-01-01 00:00:00+00, 2@2020-01-01 00:00:05+00)"
+            .parse()
+            .unwrap();
+        let trips = [GeoJsonTrip::new(trajectory).with_property("heading", heading)];
+
+        let geojson: Value =
+            serde_json::from_str(&write_geojson_trips(&trips, &GeoJsonTripsOptions::new()))
+                .unwrap();
+        let features = &geojson["features"];
+        assert_eq!(features[0]["properties"]["heading"], json!([1.0, 2.0]));
+        assert_eq!(
+            features[1]["geometry"],
+            json!({ "type": "Point", "coordinates": [5.0, 5.0] })
+        );
+        assert_eq!(features[1]["properties"]["heading"], json!([null]));
+    }
+}
@@ -0,0 +1,212 @@
+//! Reading and writing GPS Exchange Format (GPX) tracks.
+//!
+//! Each `<trk>` element corresponds to a temporal point sequence set, with one
+//! linear sequence per `<trkseg>` and the `<ele>` of its points, if present in
+//! all of them, as Z coordinate.
+
+use std::fmt::Write;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use roxmltree::{Document, Node};
+
+use crate::{
+    errors::ParseError,
+    temporal::{
+        interpolation::TInterpolation, temporal::Temporal, tinstant::TInstant,
+        tsequence::TSequence, tsequence_set::TSequenceSet,
+    },
+};
+
+use super::{
+    tgeogpoint::{TGeogPointInstant, TGeogPointSequence, TGeogPointSequenceSet},
+    tpoint::{gserialized_to_point, Point, TPointTrait, DEFAULT_GEODETIC_SRID},
+};
+
+struct TrackPoint {
+    lon: f64,
+    lat: f64,
+    ele: Option<f64>,
+    time: DateTime<Utc>,
+}
+
+fn children<'a, 'input>(
+    node: Node<'a, 'input>,
+    name: &'static str,
+) -> impl Iterator<Item = Node<'a, 'input>> {
+    node.children()
+        .filter(move |child| child.is_element() && child.tag_name().name() == name)
+}
+
+fn child_text<'a>(node: Node<'a, '_>, name: &'static str) -> Option<&'a str> {
+    children(node, name).next()?.text().map(str::trim)
+}
+
+fn read_point(point: Node) -> Result<TrackPoint, ParseError> {
+    let coordinate = |name, range: std::ops::RangeInclusive<f64>| {
+        point
+            .attribute(name)
+            .and_then(|value| value.trim().parse().ok())
+            .filter(|value| range.contains(value))
+            .ok_or(ParseError)
+    };
+    let ele = child_text(point, "ele")
+        .map(|ele| ele.parse().map_err(|_| ParseError))
+        .transpose()?;
+    let time = child_text(point, "time")
+        .and_then(|time| DateTime::parse_from_rfc3339(time).ok())
+        .ok_or(ParseError)?;
+    Ok(TrackPoint {
+        lon: coordinate("lon", -180.0..=180.0)?,
+        lat: coordinate("lat", -90.0..=90.0)?,
+        ele,
+        time: time.to_utc(),
+    })
+}
+
+fn read_track(track: Node) -> Result<Option<TGeogPointSequenceSet>, ParseError> {
+    let segments = children(track, "trkseg")
+        .map(|segment| children(segment, "trkpt").map(read_point).collect())
+        .filter(|segment: &Result<Vec<_>, _>| !segment.as_ref().is_ok_and(Vec::is_empty))
+        .collect::<Result<Vec<_>, _>>()?;
+    if segments.is_empty() {
+        return Ok(None);
+    }
+
+    // `meos` requires strictly increasing timestamps across the whole track
+    let timestamps = segments.iter().flatten().map(|point| point.time);
+    if timestamps
+        .clone()
+        .zip(timestamps.skip(1))
+        .any(|(a, b)| a >= b)
+    {
+        return Err(ParseError);
+    }
+
+    let has_z = segments.iter().flatten().all(|point| point.ele.is_some());
+    let sequences: Vec<TGeogPointSequence> = segments
+        .iter()
+        .map(|segment| {
+            let instants: Vec<_> = segment
+                .iter()
+                .map(|point| {
+                    let value = Point(point.lon, point.lat, point.ele.filter(|_| has_z));
+                    TGeogPointInstant::from_value_and_timestamp(value, point.time)
+                })
+                .collect();
+            TGeogPointSequence::new(&instants, TInterpolation::Linear)
+        })
+        .collect();
+    Ok(Some(TGeogPointSequenceSet::new(&sequences, false)))
+}
+
+/// Reads the tracks of a GPX document, one sequence set per `<trk>`.
+///
+/// Every track point must have a `<time>`. Tracks without points are skipped.
+pub fn read_gpx(gpx: &str) -> Result<Vec<TGeogPointSequenceSet>, ParseError> {
+    let document = Document::parse(gpx).map_err(|_| ParseError)?;
+    let root = document.root_element();
+    if root.tag_name().name() != "gpx" {
+        return Err(ParseError);
+    }
+    children(root, "trk")
+        .filter_map(|track| read_track(track).transpose())
+        .collect()
+}
+
+fn instant_points<I: Temporal>(instants: &[I]) -> Vec<(Point, DateTime<Utc>)> {
+    instants
+        .iter()
+        .map(|instant| {
+            let value =
+                gserialized_to_point(unsafe { meos_sys::tgeo_start_value(instant.inner()) });
+            (value, instant.start_timestamp())
+        })
+        .collect()
+}
+
+fn track_segments<T: Temporal>(track: &T) -> Vec<Vec<(Point, DateTime<Utc>)>> {
+    match track.interpolation() {
+        TInterpolation::None | TInterpolation::Discrete => vec![instant_points(&track.instants())],
+        TInterpolation::Stepwise | TInterpolation::Linear => track
+            .sequences()
+            .iter()
+            .map(|sequence| instant_points(&sequence.instants()))
+            .collect(),
+    }
+}
+
+/// Writes `tracks` as a GPX document, one `<trk>` per temporal point.
+///
+/// Each sequence is written as a `<trkseg>`, and the Z coordinate, if any, as
+/// the `<ele>` of its points. Points with an SRID other than 4326 are transformed
+/// to it, points without SRID are assumed to already be longitude/latitude.
+pub fn write_gpx<T, const IS_GEODETIC: bool>(tracks: &[T]) -> String
+where
+    T: TPointTrait<IS_GEODETIC>,
+{
+    let mut gpx = String::from(concat!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+        "<gpx version=\"1.1\" creator=\"meos-rs\" xmlns=\"http://www.topografix.com/GPX/1/1\">\n",
+    ));
+    for track in tracks {
+        let transformed;
+        let track = match track.srid() {
+            0 | DEFAULT_GEODETIC_SRID => track,
+            _ => {
+                transformed = track.transform(DEFAULT_GEODETIC_SRID);
+                &transformed
+            }
+        };
+        gpx.push_str("  <trk>\n");
+        for segment in track_segments(track) {
+            gpx.push_str("    <trkseg>\n");
+            for (Point(x, y, z), time) in segment {
+                let _ = write!(gpx, "      <trkpt lat=\"{y}\" lon=\"{x}\">");
+                if let Some(z) = z {
+                    let _ = write!(gpx, "<ele>{z}</ele>");
+                }
+                let time = time.to_rfc3339_opts(SecondsFormat::AutoSi, true);
+                let _ = writeln!(gpx, "<time>{time}</time></trkpt>");
+            }
+            gpx.push_str("    </trkseg>\n");
+        }
+        gpx.push_str("  </trk>\n");
+    }
+    gpx.push_str("</gpx>\n");
+    gpx
+}
+
+#[cfg(test)]
+#[serial_test::serial]
+mod tests {
+    use super::*;
+    use crate::{meos_initialize, meos_initialize_timezone};
+
+    #[test]
+    fn gpx_round_trip() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let gpx = r#"<?xml version="1.0"?>
+            <gpx version="1.1" xmlns="http://www.topografix.com/GPX/1/1">
+              <trk>
+                <trkseg>
+                  <trkpt lat="50.5" lon="4.25"><ele>10</ele><time>2020-01-01T00:00:00Z</time></trkpt>
+                  <trkpt lat="50.6" lon="4.5"><ele>12</ele><time>2020-01-01T00:01:00Z</time></trkpt>
+                </trkseg>
+                <trkseg>
+                  <trkpt lat="50.7" lon="4.75"><ele>11</ele><time>2020-01-01T00:05:00Z</time></trkpt>
+                </trkseg>
+              </trk>
+            </gpx>"#;
+        let tracks = read_gpx(gpx).unwrap();
+        assert_eq!(tracks.len(), 1);
+        assert_eq!(tracks[0].num_instants(), 3);
+        assert_eq!(tracks[0].start_value(), Point(4.25, 50.5, Some(10.0)));
+
+        let read = read_gpx(&write_gpx(&tracks)).unwrap();
+        assert_eq!(read[0], tracks[0]);
+
+        let unordered = gpx.replace("00:05:00", "00:00:30");
+        assert_eq!(read_gpx(&unordered), Err(ParseError));
+    }
+}
@@ -0,0 +1,354 @@
+use core::fmt;
+use std::{
+    ffi::{c_void, CStr, CString},
+    ptr, slice,
+};
+
+use crate::errors::ParseError;
+
+use super::tpoint::Point;
+
+/// An owned `meos` geometry or geography (`GSERIALIZED`) value.
+///
+/// It is mostly used as an intermediate representation when converting
+/// between the geometry types of other crates and `meos`.
+pub struct GSerialized {
+    _inner: ptr::NonNull<meos_sys::GSERIALIZED>,
+}
+
+impl GSerialized {
+    pub fn from_inner(inner: *mut meos_sys::GSERIALIZED) -> Self {
+        Self {
+            _inner: ptr::NonNull::new(inner).expect("Null pointers not allowed"),
+        }
+    }
+
+    pub fn inner(&self) -> *const meos_sys::GSERIALIZED {
+        self._inner.as_ptr()
+    }
+
+    /// Creates a geometry from its (E)WKB representation.
+    ///
+    /// ## Arguments
+    ///
+    /// * `wkb` - The (E)WKB bytes.
+    /// * `srid` - The SRID to use when the bytes don't include one.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `geo_from_ewkb`
+    pub fn from_wkb(wkb: &[u8], srid: i32) -> Self {
+        crate::meos_initialize();
+        Self::from_inner(unsafe { meos_sys::geo_from_ewkb(wkb.as_ptr(), wkb.len(), srid) })
+    }
+
+    /// Returns the little endian EWKB representation of the value.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `geo_as_ewkb`
+    pub fn as_wkb(&self) -> Vec<u8> {
+        let mut size = 0;
+        let endian = CString::new("NDR").unwrap();
+        unsafe {
+            let bytes =
+                meos_sys::geo_as_ewkb(self.inner(), endian.as_ptr(), ptr::addr_of_mut!(size));
+            let result = slice::from_raw_parts(bytes, size).to_vec();
+            libc::free(bytes.cast::<c_void>());
+            result
+        }
+    }
+
+    /// Returns the value as an EWKT string.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `geo_as_ewkt`
+    pub fn as_ewkt(&self, precision: i32) -> String {
+        unsafe {
+            let out_str = meos_sys::geo_as_ewkt(self.inner(), precision);
+            let result = CStr::from_ptr(out_str).to_str().unwrap().to_owned();
+            libc::free(out_str.cast::<c_void>());
+            result
+        }
+    }
+
+    /// Returns the SRID of the value.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `geo_srid`
+    pub fn srid(&self) -> i32 {
+        unsafe { meos_sys::geo_srid(self.inner()) }
+    }
+
+    /// Returns a copy of the value with the given SRID.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `geo_set_srid`
+    pub fn with_srid(&self, srid: i32) -> Self {
+        Self::from_inner(unsafe { meos_sys::geo_set_srid(self.inner(), srid) })
+    }
+
+    /// Returns the value converted to a geography.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `geom_to_geog`
+    pub fn to_geography(&self) -> Self {
+        Self::from_inner(unsafe { meos_sys::geom_to_geog(self.inner()) })
+    }
+
+    /// Returns the value converted to a geometry.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `geog_to_geom`
+    pub fn to_geometry(&self) -> Self {
+        Self::from_inner(unsafe { meos_sys::geog_to_geom(self.inner()) })
+    }
+
+    /// Returns whether the value is empty.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `geo_is_empty`
+    pub fn is_empty(&self) -> bool {
+        unsafe { meos_sys::geo_is_empty(self.inner()) }
+    }
+}
+
+impl Clone for GSerialized {
+    fn clone(&self) -> Self {
+        Self::from_inner(unsafe { meos_sys::geo_copy(self.inner()) })
+    }
+}
+
+impl Drop for GSerialized {
+    fn drop(&mut self) {
+        unsafe {
+            libc::free(self._inner.as_ptr().cast::<c_void>());
+        }
+    }
+}
+
+crate::impl_send_sync!(GSerialized);
+
+impl fmt::Debug for GSerialized {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.as_ewkt(15))
+    }
+}
+
+impl From<Point> for GSerialized {
+    fn from(point: Point) -> Self {
+        crate::meos_initialize();
+        Self::from_wkb(&point.to_wkb(), 0)
+    }
+}
+
+impl TryFrom<&GSerialized> for Point {
+    type Error = ParseError;
+
+    fn try_from(value: &GSerialized) -> Result<Self, Self::Error> {
+        Point::from_wkb(&value.as_wkb()).ok_or(ParseError)
+    }
+}
+
+#[cfg(feature = "geo-types")]
+mod geo_types_conversions {
+    use geo_traits::to_geo::ToGeoGeometry;
+    use geo_types::{Geometry, LineString, Polygon};
+    use wkb::{reader::read_wkb, writer::WriteOptions, Endianness};
+
+    use super::{GSerialized, ParseError, Point};
+
+    impl From<geo_types::Point> for Point {
+        fn from(point: geo_types::Point) -> Self {
+            Self(point.x(), point.y(), None)
+        }
+    }
+
+    /// The `z` coordinate, if any, is dropped since `geo_types` is two dimensional.
+    impl From<Point> for geo_types::Point {
+        fn from(point: Point) -> Self {
+            geo_types::Point::new(point.0, point.1)
+        }
+    }
+
+    impl From<&Geometry> for GSerialized {
+        fn from(geometry: &Geometry) -> Self {
+            let mut bytes = Vec::new();
+            let options = WriteOptions {
+                endianness: Endianness::LittleEndian,
+            };
+            wkb::writer::write_geometry(&mut bytes, geometry, &options)
+                .expect("2D geometries can be written as WKB");
+            Self::from_wkb(&bytes, 0)
+        }
+    }
+
+    macro_rules! impl_geo_types_conversions {
+        ($($type:ident),+) => {
+            $(
+                impl From<$type> for GSerialized {
+                    fn from(value: $type) -> Self {
+                        Self::from(&Geometry::from(value))
+                    }
+                }
+
+                impl From<&$type> for GSerialized {
+                    fn from(value: &$type) -> Self {
+                        Self::from(&Geometry::from(value.clone()))
+                    }
+                }
+
+                impl TryFrom<&GSerialized> for $type {
+                    type Error = ParseError;
+
+                    fn try_from(value: &GSerialized) -> Result<Self, Self::Error> {
+                        $type::try_from(Geometry::try_from(value)?).map_err(|_| ParseError)
+                    }
+                }
+
+                impl TryFrom<GSerialized> for $type {
+                    type Error = ParseError;
+
+                    fn try_from(value: GSerialized) -> Result<Self, Self::Error> {
+                        Self::try_from(&value)
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_geo_types_conversions!(LineString, Polygon);
+
+    impl From<geo_types::Point> for GSerialized {
+        fn from(value: geo_types::Point) -> Self {
+            Self::from(&Geometry::from(value))
+        }
+    }
+
+    impl From<&geo_types::Point> for GSerialized {
+        fn from(value: &geo_types::Point) -> Self {
+            Self::from(*value)
+        }
+    }
+
+    impl TryFrom<&GSerialized> for geo_types::Point {
+        type Error = ParseError;
+
+        fn try_from(value: &GSerialized) -> Result<Self, Self::Error> {
+            geo_types::Point::try_from(Geometry::try_from(value)?).map_err(|_| ParseError)
+        }
+    }
+
+    impl TryFrom<GSerialized> for geo_types::Point {
+        type Error = ParseError;
+
+        fn try_from(value: GSerialized) -> Result<Self, Self::Error> {
+            Self::try_from(&value)
+        }
+    }
+
+    impl From<Geometry> for GSerialized {
+        fn from(value: Geometry) -> Self {
+            Self::from(&value)
+        }
+    }
+
+    /// The `z` and `m` coordinates, if any, are dropped since `geo_types` is two
+    /// dimensional, and empty points cannot be converted.
+    impl TryFrom<&GSerialized> for Geometry {
+        type Error = ParseError;
+
+        fn try_from(value: &GSerialized) -> Result<Self, Self::Error> {
+            let bytes = value.as_wkb();
+            read_wkb(&bytes)
+                .map_err(|_| ParseError)?
+                .try_to_geometry()
+                .ok_or(ParseError)
+        }
+    }
+
+    impl TryFrom<GSerialized> for Geometry {
+        type Error = ParseError;
+
+        fn try_from(value: GSerialized) -> Result<Self, Self::Error> {
+            Self::try_from(&value)
+        }
+    }
+}
+
+#[cfg(test)]
+#[serial_test::serial]
+mod tests {
+    use crate::{meos_initialize, meos_initialize_timezone, Point};
+
+    use super::*;
+
+    #[test]
+    fn point_round_trip() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let point = Point(1.0, 2.0, Some(3.0));
+        let gs = GSerialized::from(point);
+        assert_eq!(gs.as_ewkt(5), "POINT(1 2 3)");
+        assert_eq!(Point::try_from(&gs), Ok(point));
+    }
+
+    #[cfg(feature = "geo-types")]
+    #[test]
+    fn geo_types_round_trip() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let polygon = geo_types::Polygon::new(
+            vec![(0., 0.), (4., 0.), (4., 4.), (0., 0.)].into(),
+            vec![vec![(1., 1.), (2., 1.), (2., 2.), (1., 1.)].into()],
+        );
+        let gs = GSerialized::from(&polygon);
+        assert_eq!(geo_types::Polygon::try_from(&gs), Ok(polygon));
+
+        let collection =
+            geo_types::Geometry::GeometryCollection(geo_types::GeometryCollection(vec![
+                geo_types::Point::new(1., 2.).into(),
+                geo_types::MultiLineString(vec![vec![(0., 0.), (1., 1.)].into()]).into(),
+                geo_types::MultiPolygon(vec![geo_types::Polygon::new(
+                    vec![(0., 0.), (1., 0.), (1., 1.), (0., 0.)].into(),
+                    Vec::new(),
+                )])
+                .into(),
+            ]));
+        let gs = GSerialized::from(&collection);
+        assert_eq!(gs.as_ewkt(5), "GEOMETRYCOLLECTION(POINT(1 2),MULTILINESTRING((0 0,1 1)),MULTIPOLYGON(((0 0,1 0,1 1,0 0))))");
+        assert_eq!(geo_types::Geometry::try_from(&gs), Ok(collection));
+    }
+
+    #[cfg(feature = "geo-types")]
+    #[test]
+    fn geo_types_from_ewkb() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let gs = GSerialized::from(Point(1.0, 2.0, Some(3.0))).with_srid(4326);
+        assert_eq!(
+            geo_types::Point::try_from(&gs),
+            Ok(geo_types::Point::new(1.0, 2.0))
+        );
+        let line = GSerialized::from_wkb(
+            &GSerialized::from(&geo_types::LineString::from(vec![(0., 0.), (1., 1.)])).as_wkb(),
+            3857,
+        );
+        assert_eq!(line.srid(), 3857);
+        assert!(geo_types::Point::try_from(&line).is_err());
+        assert_eq!(
+            geo_types::LineString::try_from(line),
+            Ok(vec![(0., 0.), (1., 1.)].into())
+        );
+        assert_eq!(
+            geo_types::Point::from(Point(1.0, 2.0, Some(3.0))),
+            geo_types::Point::new(1.0, 2.0)
+        );
+    }
+}
@@ -1,11 +1,21 @@
+#[cfg(feature = "serde")]
+pub mod geojson;
+#[cfg(feature = "gpx")]
+pub mod gpx;
+pub mod gserialized;
 pub mod tgeogpoint;
 pub mod tgeompoint;
 pub mod tpoint;
+pub mod trip_assembler;
 
 #[cfg(test)]
 #[serial_test::serial]
 mod tests {
-    use crate::{meos_initialize, meos_initialize_timezone};
+    use chrono::TimeZone;
+
+    use crate::{
+        meos_initialize, meos_initialize_timezone, OrderedTemporal, TPointTrait, Temporal,
+    };
 
     use super::*;
 
@@ -80,4 +90,122 @@ mod tests {
             format!("SequenceSet({})", string.to_owned())
         );
     }
+
+    #[test]
+    fn point_values() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let string = "[POINT(1 2)@2018-01-01 08:00:00+00, POINT(3 4)@2018-01-01 08:10:00+00]";
+        let result: tgeompoint::TGeomPoint = string.parse().unwrap();
+        assert_eq!(result.start_value(), tpoint::Point(1.0, 2.0, None));
+        assert_eq!(result.end_value(), tpoint::Point(3.0, 4.0, None));
+
+        let string = "POINT Z(1 2 3)@2018-01-01 08:00:00+00";
+        let result: tgeompoint::TGeomPoint = string.parse().unwrap();
+        assert_eq!(result.start_value(), tpoint::Point(1.0, 2.0, Some(3.0)));
+    }
+
+    #[test]
+    fn acceleration() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let string = "[POINT(0 0)@2018-01-01 08:00:00+00, POINT(10 0)@2018-01-01 08:00:10+00, \
+                      POINT(40 0)@2018-01-01 08:00:20+00, POINT(70 0)@2018-01-01 08:00:30+00]";
+        let result: tgeompoint::TGeomPoint = string.parse().unwrap();
+        let acceleration = result.acceleration().unwrap();
+        assert!((acceleration.start_value() - 0.2).abs() < 1e-9);
+        assert!(acceleration.end_value().abs() < 1e-9);
+        assert!(result.start_instant().acceleration().is_none());
+
+        let string = "{[POINT(0 0)@2018-01-01 08:00:00+00, POINT(10 0)@2018-01-01 08:00:10+00, \
+                      POINT(40 0)@2018-01-01 08:00:20+00], \
+                      [POINT(100 0)@2018-01-01 09:00:00+00, POINT(110 0)@2018-01-01 09:00:05+00]}";
+        let result: tgeompoint::TGeomPoint = string.parse().unwrap();
+        let acceleration = result.acceleration().unwrap();
+        assert_eq!(acceleration.num_sequences(), 2);
+        assert!((acceleration.start_value() - 0.2).abs() < 1e-9);
+        assert!(acceleration.end_value().abs() < 1e-9);
+        let t = chrono::Utc.with_ymd_and_hms(2018, 1, 1, 8, 0, 5).unwrap();
+        assert_eq!(acceleration.start_timestamp(), t);
+        assert_eq!(
+            acceleration.end_timestamp(),
+            t + chrono::TimeDelta::hours(1)
+        );
+    }
+
+    #[test]
+    fn split_boxes() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let string = "[POINT(0 0)@2018-01-01 08:00:00+00, POINT(1 1)@2018-01-01 08:01:00+00, \
+                      POINT(2 2)@2018-01-01 08:02:00+00, POINT(3 3)@2018-01-01 08:03:00+00]";
+        let result: tgeompoint::TGeomPoint = string.parse().unwrap();
+        assert_eq!(result.stboxes().len(), 3);
+        assert_eq!(result.split_n_stboxes(2).len(), 2);
+        assert_eq!(result.split_each_n_spans(2).len(), 2);
+        assert!(!result
+            .space_boxes(2., 2., 2., None, false, false)
+            .is_empty());
+    }
+
+    #[test]
+    fn geometry_geography_conversions() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let string =
+            "SRID=4326;[POINT(1 2)@2018-01-01 08:00:00+00, POINT(3 4)@2018-01-01 08:10:00+00]";
+        let result: tgeompoint::TGeomPoint = string.parse().unwrap();
+        let geography = tgeogpoint::TGeogPoint::try_from(&result).unwrap();
+        assert_eq!(geography.end_value(), tpoint::Point(3.0, 4.0, None));
+        assert_eq!(tgeompoint::TGeomPoint::from(geography), result);
+    }
+
+    #[test]
+    fn from_base_temporal() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let string = "{[POINT(1 2)@2018-01-01 08:00:00+00, POINT(3 4)@2018-01-01 08:10:00+00]}";
+        let trip: tgeompoint::TGeomPoint = string.parse().unwrap();
+        let constant = crate::TInt::from_base_temporal(1, &trip);
+        assert_eq!(
+            format!("{constant:?}"),
+            "SequenceSet({[1@2018-01-01 08:00:00+00, 1@2018-01-01 08:10:00+00]})"
+        );
+        let origin =
+            tgeompoint::TGeomPoint::from_base_temporal(&tpoint::Point(0., 0., None), &trip);
+        assert_eq!(origin.time(), trip.time());
+        assert_eq!(origin.srid(), 0);
+
+        let trip = trip.with_srid(4326);
+        let origin =
+            tgeompoint::TGeomPoint::from_base_temporal(&tpoint::Point(0., 0., None), &trip);
+        assert_eq!(origin.srid(), 4326);
+        let origin =
+            tgeompoint::TGeomPoint::from_base_temporal(&tpoint::Point(0., 0., None), &constant);
+        assert_eq!(origin.srid(), 0);
+    }
+
+    #[test]
+    fn values_with_srid() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let string =
+            "SRID=4326;[POINT(1 2)@2018-01-01 08:00:00+00, POINT(3 4)@2018-01-01 08:10:00+00]";
+        let trip: tgeompoint::TGeomPoint = string.parse().unwrap();
+        let start = tpoint::Point(1., 2., None);
+        assert!(crate::Collection::contains(&trip, &start));
+        assert_eq!(trip.ever_equal_than_value(start), Some(true));
+        assert_eq!(trip.at_value(&start).unwrap().num_instants(), 1);
+        assert_eq!(trip.minus_point(start).num_instants(), 1);
+        assert!(trip.distance_to_point(start).min_value().abs() < f64::EPSILON);
+
+        let timestamp = chrono::Utc.with_ymd_and_hms(2018, 1, 1, 8, 0, 0).unwrap();
+        let instant =
+            tgeompoint::TGeomPointInstant::from_value_timestamp_and_srid(start, &timestamp, 4326);
+        assert_eq!(instant.srid(), 4326);
+        assert!(trip
+            .start_instant()
+            .temporal_equal_value(&start)
+            .start_value());
+    }
 }
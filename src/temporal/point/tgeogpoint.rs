@@ -13,19 +13,25 @@ use crate::{
     temporal::{
         tbool::{TBool, TBoolInstant, TBoolSequence, TBoolSequenceSet},
         temporal::{
-            impl_always_and_ever_value_equality_functions, impl_simple_traits_for_temporal,
-            SimplifiableTemporal, Temporal,
+            impl_always_and_ever_value_equality_functions, impl_from_base_temporal,
+            impl_simple_traits_for_temporal, impl_temporal_conversion, SimplifiableTemporal,
+            Temporal,
         },
     },
+    try_factory,
     utils::to_meos_timestamp,
     MeosEnum,
 };
 use chrono::{DateTime, TimeZone};
+#[cfg(feature = "geos")]
 use geos::Geometry;
 
+use super::tgeompoint::{TGeomPoint, TGeomPointInstant, TGeomPointSequence, TGeomPointSequenceSet};
+#[cfg(feature = "geos")]
+use super::tpoint::geometry_to_gserialized;
 use super::tpoint::{
-    create_set_of_geometries, geometry_to_gserialized, gserialized_to_geometry, impl_tpoint_traits,
-    TPointTrait,
+    create_set_of_points, default_srid, gserialized_to_point, impl_tpoint_traits, point_like,
+    point_to_gserialized, Point, TPointTrait,
 };
 
 pub struct TGeogPointInstant {
@@ -46,6 +52,44 @@ impl TInstant for TGeogPointInstant {
     }
 
     fn from_value_and_timestamp<Tz: TimeZone>(value: Self::Type, timestamp: DateTime<Tz>) -> Self {
+        Self::from_value_timestamp_and_srid(value, &timestamp, default_srid(true))
+    }
+}
+
+impl TGeogPointInstant {
+    /// Creates a temporal point instant with the value `value` in the spatial
+    /// reference system `srid` at `timestamp`.
+    #[doc(alias = "tpointinst_make")]
+    pub fn from_value_timestamp_and_srid<Tz: TimeZone>(
+        value: Point,
+        timestamp: &DateTime<Tz>,
+        srid: i32,
+    ) -> Self {
+        let point = point_to_gserialized(&value, srid, true);
+        let result = Self::from_inner(unsafe {
+            meos_sys::tpointinst_make(point, to_meos_timestamp(timestamp))
+        });
+        unsafe { libc::free(point.cast()) };
+        result
+    }
+}
+
+impl<Tz: TimeZone> From<(Point, DateTime<Tz>)> for TGeogPointInstant {
+    fn from((value, timestamp): (Point, DateTime<Tz>)) -> Self {
+        Self::from_value_and_timestamp(value, timestamp)
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl<Tz: TimeZone> From<(geo_types::Point, DateTime<Tz>)> for TGeogPointInstant {
+    fn from((value, timestamp): (geo_types::Point, DateTime<Tz>)) -> Self {
+        Self::from_value_and_timestamp(value.into(), timestamp)
+    }
+}
+
+#[cfg(feature = "geos")]
+impl<Tz: TimeZone> From<(Geometry, DateTime<Tz>)> for TGeogPointInstant {
+    fn from((value, timestamp): (Geometry, DateTime<Tz>)) -> Self {
         Self::from_inner(unsafe {
             meos_sys::tpointinst_make(
                 geometry_to_gserialized(&value),
@@ -55,12 +99,6 @@ impl TInstant for TGeogPointInstant {
     }
 }
 
-impl<Tz: TimeZone> From<(Geometry, DateTime<Tz>)> for TGeogPointInstant {
-    fn from((value, timestamp): (Geometry, DateTime<Tz>)) -> Self {
-        Self::from_value_and_timestamp(value, timestamp)
-    }
-}
-
 impl TPointTrait<true> for TGeogPointInstant {}
 
 pub struct TGeogPointSequence {
@@ -140,6 +178,8 @@ pub enum TGeogPoint {
 
 impl_from_str!(TGeogPoint);
 
+impl_from_base_temporal!(TGeogPoint, point, true);
+
 impl TPointTrait<true> for TGeogPoint {}
 
 impl MeosEnum for TGeogPoint {
@@ -155,9 +195,9 @@ impl MeosEnum for TGeogPoint {
         Self::SequenceSet(TGeogPointSequenceSet::from_inner(inner))
     }
 
-    fn from_mfjson(mfjson: &str) -> Self {
-        let cstr = CString::new(mfjson).unwrap();
-        factory::<Self>(unsafe { meos_sys::tint_from_mfjson(cstr.as_ptr()) })
+    fn try_from_mfjson(mfjson: &str) -> Result<Self, ParseError> {
+        let cstr = CString::new(mfjson).map_err(|_| ParseError)?;
+        try_factory::<Self>(|| unsafe { meos_sys::tgeogpoint_from_mfjson(cstr.as_ptr()) })
     }
 }
 
@@ -175,6 +215,24 @@ impl<'a> FromIterator<&'a TGeogPointInstant> for TGeogPointSequence {
     }
 }
 
+impl<Tz: TimeZone> FromIterator<(Point, DateTime<Tz>)> for TGeogPointSequence {
+    fn from_iter<T: IntoIterator<Item = (Point, DateTime<Tz>)>>(iter: T) -> Self {
+        iter.into_iter()
+            .map(Into::<TGeogPointInstant>::into)
+            .collect()
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl<Tz: TimeZone> FromIterator<(geo_types::Point, DateTime<Tz>)> for TGeogPointSequence {
+    fn from_iter<T: IntoIterator<Item = (geo_types::Point, DateTime<Tz>)>>(iter: T) -> Self {
+        iter.into_iter()
+            .map(Into::<TGeogPointInstant>::into)
+            .collect()
+    }
+}
+
+#[cfg(feature = "geos")]
 impl<Tz: TimeZone> FromIterator<(Geometry, DateTime<Tz>)> for TGeogPointSequence {
     fn from_iter<T: IntoIterator<Item = (Geometry, DateTime<Tz>)>>(iter: T) -> Self {
         iter.into_iter()
@@ -183,13 +241,26 @@ impl<Tz: TimeZone> FromIterator<(Geometry, DateTime<Tz>)> for TGeogPointSequence
     }
 }
 
+/// Converts a temporal geometry point into a temporal geography point, going
+/// through the temporal geometry and geography types of `meos`.
+unsafe fn tgeompoint_to_tgeogpoint(temp: *const meos_sys::Temporal) -> *mut meos_sys::Temporal {
+    let geometry = meos_sys::tgeompoint_to_tgeometry(temp);
+    let geography = meos_sys::tgeometry_to_tgeography(geometry);
+    libc::free(geometry.cast());
+    let result = meos_sys::tgeography_to_tgeogpoint(geography);
+    libc::free(geography.cast());
+    result
+}
+
+impl_temporal_conversion!(TryFrom, TGeomPoint => TGeogPoint, tgeompoint_to_tgeogpoint);
+
 impl Collection for TGeogPoint {
-    impl_collection!(tspatial, Geometry);
+    impl_collection!(tspatial, Point);
     fn contains(&self, element: &Self::Type) -> bool {
         unsafe {
             meos_sys::contains_tspatial_stbox(
                 self.inner(),
-                meos_sys::geo_to_stbox(geometry_to_gserialized(element)),
+                meos_sys::geo_to_stbox(point_like(self, element)),
             )
         }
     }
@@ -206,7 +277,7 @@ impl Temporal for TGeogPoint {
     type Enum = TGeogPoint;
     type TBoolType = TBool;
 
-    impl_always_and_ever_value_equality_functions!(geo, geometry_to_gserialized);
+    impl_always_and_ever_value_equality_functions!(geo, with_self point_like);
     fn from_inner_as_temporal(inner: *mut meos_sys::Temporal) -> Self {
         factory::<Self>(inner)
     }
@@ -230,18 +301,17 @@ impl Temporal for TGeogPoint {
 
             std::slice::from_raw_parts(values, count as usize)
                 .iter()
-                .map(|gs: &*mut _| gserialized_to_geometry(*gs))
-                .map(Result::unwrap)
+                .map(|&gs| gserialized_to_point(gs))
                 .collect()
         }
     }
 
     fn start_value(&self) -> Self::Type {
-        gserialized_to_geometry(unsafe { meos_sys::tgeo_start_value(self.inner()) }).unwrap()
+        gserialized_to_point(unsafe { meos_sys::tgeo_start_value(self.inner()) })
     }
 
     fn end_value(&self) -> Self::Type {
-        gserialized_to_geometry(unsafe { meos_sys::tgeo_end_value(self.inner()) }).unwrap()
+        gserialized_to_point(unsafe { meos_sys::tgeo_end_value(self.inner()) })
     }
 
     fn value_at_timestamp<Tz: TimeZone>(&self, timestamp: DateTime<Tz>) -> Option<Self::Type> {
@@ -254,7 +324,7 @@ impl Temporal for TGeogPoint {
                 result.as_mut_ptr(),
             );
             if success {
-                Some(gserialized_to_geometry(result.assume_init()).unwrap())
+                Some(gserialized_to_point(result.assume_init()))
             } else {
                 None
             }
@@ -262,8 +332,7 @@ impl Temporal for TGeogPoint {
     }
 
     fn at_value(&self, value: &Self::Type) -> Option<Self::Enum> {
-        let result =
-            unsafe { meos_sys::tpoint_at_value(self.inner(), geometry_to_gserialized(value)) };
+        let result = unsafe { meos_sys::tpoint_at_value(self.inner(), point_like(self, value)) };
         if result.is_null() {
             None
         } else {
@@ -272,7 +341,7 @@ impl Temporal for TGeogPoint {
     }
     fn at_values(&self, values: &[Self::Type]) -> Option<Self::Enum> {
         unsafe {
-            let set = create_set_of_geometries(values);
+            let set = create_set_of_points(self, values);
             let result = meos_sys::temporal_at_values(self.inner(), set);
             if result.is_null() {
                 None
@@ -284,26 +353,26 @@ impl Temporal for TGeogPoint {
 
     fn minus_value(&self, value: Self::Type) -> Self::Enum {
         factory::<Self::Enum>(unsafe {
-            meos_sys::tpoint_minus_value(self.inner(), geometry_to_gserialized(&value))
+            meos_sys::tpoint_minus_value(self.inner(), point_like(self, &value))
         })
     }
 
     fn minus_values(&self, values: &[Self::Type]) -> Self::Enum {
         factory::<Self::Enum>(unsafe {
-            let set = create_set_of_geometries(values);
+            let set = create_set_of_points(self, values);
             meos_sys::temporal_minus_values(self.inner(), set)
         })
     }
 
     fn temporal_equal_value(&self, value: &Self::Type) -> Self::TBoolType {
         Self::TBoolType::from_inner_as_temporal(unsafe {
-            meos_sys::teq_tgeo_geo(self.inner(), geometry_to_gserialized(value))
+            meos_sys::teq_tgeo_geo(self.inner(), point_like(self, value))
         })
     }
 
     fn temporal_not_equal_value(&self, value: &Self::Type) -> Self::TBoolType {
         Self::TBoolType::from_inner_as_temporal(unsafe {
-            meos_sys::tne_tgeo_geo(self.inner(), geometry_to_gserialized(value))
+            meos_sys::tne_tgeo_geo(self.inner(), point_like(self, value))
         })
     }
 }
@@ -13,19 +13,25 @@ use crate::{
     temporal::{
         tbool::{TBool, TBoolInstant, TBoolSequence, TBoolSequenceSet},
         temporal::{
-            impl_always_and_ever_value_equality_functions, impl_simple_traits_for_temporal,
-            SimplifiableTemporal, Temporal,
+            impl_always_and_ever_value_equality_functions, impl_from_base_temporal,
+            impl_simple_traits_for_temporal, impl_temporal_conversion, SimplifiableTemporal,
+            Temporal,
         },
     },
+    try_factory,
     utils::to_meos_timestamp,
     MeosEnum,
 };
 use chrono::{DateTime, TimeZone};
+#[cfg(feature = "geos")]
 use geos::Geometry;
 
+use super::tgeogpoint::{TGeogPoint, TGeogPointInstant, TGeogPointSequence, TGeogPointSequenceSet};
+#[cfg(feature = "geos")]
+use super::tpoint::geometry_to_gserialized;
 use super::tpoint::{
-    create_set_of_geometries, geometry_to_gserialized, gserialized_to_geometry, impl_tpoint_traits,
-    TPointTrait,
+    create_set_of_points, default_srid, gserialized_to_point, impl_tpoint_traits, point_like,
+    point_to_gserialized, Point, TPointTrait,
 };
 
 pub struct TGeomPointInstant {
@@ -46,6 +52,44 @@ impl TInstant for TGeomPointInstant {
     }
 
     fn from_value_and_timestamp<Tz: TimeZone>(value: Self::Type, timestamp: DateTime<Tz>) -> Self {
+        Self::from_value_timestamp_and_srid(value, &timestamp, default_srid(false))
+    }
+}
+
+impl TGeomPointInstant {
+    /// Creates a temporal point instant with the value `value` in the spatial
+    /// reference system `srid` at `timestamp`.
+    #[doc(alias = "tpointinst_make")]
+    pub fn from_value_timestamp_and_srid<Tz: TimeZone>(
+        value: Point,
+        timestamp: &DateTime<Tz>,
+        srid: i32,
+    ) -> Self {
+        let point = point_to_gserialized(&value, srid, false);
+        let result = Self::from_inner(unsafe {
+            meos_sys::tpointinst_make(point, to_meos_timestamp(timestamp))
+        });
+        unsafe { libc::free(point.cast()) };
+        result
+    }
+}
+
+impl<Tz: TimeZone> From<(Point, DateTime<Tz>)> for TGeomPointInstant {
+    fn from((value, timestamp): (Point, DateTime<Tz>)) -> Self {
+        Self::from_value_and_timestamp(value, timestamp)
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl<Tz: TimeZone> From<(geo_types::Point, DateTime<Tz>)> for TGeomPointInstant {
+    fn from((value, timestamp): (geo_types::Point, DateTime<Tz>)) -> Self {
+        Self::from_value_and_timestamp(value.into(), timestamp)
+    }
+}
+
+#[cfg(feature = "geos")]
+impl<Tz: TimeZone> From<(Geometry, DateTime<Tz>)> for TGeomPointInstant {
+    fn from((value, timestamp): (Geometry, DateTime<Tz>)) -> Self {
         Self::from_inner(unsafe {
             meos_sys::tpointinst_make(
                 geometry_to_gserialized(&value),
@@ -55,12 +99,6 @@ impl TInstant for TGeomPointInstant {
     }
 }
 
-impl<Tz: TimeZone> From<(Geometry, DateTime<Tz>)> for TGeomPointInstant {
-    fn from((value, timestamp): (Geometry, DateTime<Tz>)) -> Self {
-        Self::from_value_and_timestamp(value, timestamp)
-    }
-}
-
 impl TPointTrait<false> for TGeomPointInstant {}
 
 pub struct TGeomPointSequence {
@@ -140,6 +178,8 @@ pub enum TGeomPoint {
 
 impl_from_str!(TGeomPoint);
 
+impl_from_base_temporal!(TGeomPoint, point, false);
+
 impl TPointTrait<false> for TGeomPoint {}
 
 impl MeosEnum for TGeomPoint {
@@ -155,9 +195,9 @@ impl MeosEnum for TGeomPoint {
         Self::SequenceSet(TGeomPointSequenceSet::from_inner(inner))
     }
 
-    fn from_mfjson(mfjson: &str) -> Self {
-        let cstr = CString::new(mfjson).unwrap();
-        factory::<Self>(unsafe { meos_sys::tint_from_mfjson(cstr.as_ptr()) })
+    fn try_from_mfjson(mfjson: &str) -> Result<Self, ParseError> {
+        let cstr = CString::new(mfjson).map_err(|_| ParseError)?;
+        try_factory::<Self>(|| unsafe { meos_sys::tgeompoint_from_mfjson(cstr.as_ptr()) })
     }
 }
 
@@ -175,6 +215,24 @@ impl<'a> FromIterator<&'a TGeomPointInstant> for TGeomPointSequence {
     }
 }
 
+impl<Tz: TimeZone> FromIterator<(Point, DateTime<Tz>)> for TGeomPointSequence {
+    fn from_iter<T: IntoIterator<Item = (Point, DateTime<Tz>)>>(iter: T) -> Self {
+        iter.into_iter()
+            .map(Into::<TGeomPointInstant>::into)
+            .collect()
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl<Tz: TimeZone> FromIterator<(geo_types::Point, DateTime<Tz>)> for TGeomPointSequence {
+    fn from_iter<T: IntoIterator<Item = (geo_types::Point, DateTime<Tz>)>>(iter: T) -> Self {
+        iter.into_iter()
+            .map(Into::<TGeomPointInstant>::into)
+            .collect()
+    }
+}
+
+#[cfg(feature = "geos")]
 impl<Tz: TimeZone> FromIterator<(Geometry, DateTime<Tz>)> for TGeomPointSequence {
     fn from_iter<T: IntoIterator<Item = (Geometry, DateTime<Tz>)>>(iter: T) -> Self {
         iter.into_iter()
@@ -183,13 +241,26 @@ impl<Tz: TimeZone> FromIterator<(Geometry, DateTime<Tz>)> for TGeomPointSequence
     }
 }
 
+/// Converts a temporal geography point into a temporal geometry point, going
+/// through the temporal geography and geometry types of `meos`.
+unsafe fn tgeogpoint_to_tgeompoint(temp: *const meos_sys::Temporal) -> *mut meos_sys::Temporal {
+    let geography = meos_sys::tgeogpoint_to_tgeography(temp);
+    let geometry = meos_sys::tgeography_to_tgeometry(geography);
+    libc::free(geography.cast());
+    let result = meos_sys::tgeometry_to_tgeompoint(geometry);
+    libc::free(geometry.cast());
+    result
+}
+
+impl_temporal_conversion!(From, TGeogPoint => TGeomPoint, tgeogpoint_to_tgeompoint);
+
 impl Collection for TGeomPoint {
-    impl_collection!(tspatial, Geometry);
+    impl_collection!(tspatial, Point);
     fn contains(&self, element: &Self::Type) -> bool {
         unsafe {
             meos_sys::contains_tspatial_stbox(
                 self.inner(),
-                meos_sys::geo_to_stbox(geometry_to_gserialized(element)),
+                meos_sys::geo_to_stbox(point_like(self, element)),
             )
         }
     }
@@ -206,7 +277,7 @@ impl Temporal for TGeomPoint {
     type Enum = TGeomPoint;
     type TBoolType = TBool;
 
-    impl_always_and_ever_value_equality_functions!(geo, geometry_to_gserialized);
+    impl_always_and_ever_value_equality_functions!(geo, with_self point_like);
     fn from_inner_as_temporal(inner: *mut meos_sys::Temporal) -> Self {
         factory::<Self>(inner)
     }
@@ -230,18 +301,17 @@ impl Temporal for TGeomPoint {
 
             std::slice::from_raw_parts(values, count as usize)
                 .iter()
-                .map(|&gs| gserialized_to_geometry(gs))
-                .map(Result::unwrap)
+                .map(|&gs| gserialized_to_point(gs))
                 .collect()
         }
     }
 
     fn start_value(&self) -> Self::Type {
-        gserialized_to_geometry(unsafe { meos_sys::tgeo_start_value(self.inner()) }).unwrap()
+        gserialized_to_point(unsafe { meos_sys::tgeo_start_value(self.inner()) })
     }
 
     fn end_value(&self) -> Self::Type {
-        gserialized_to_geometry(unsafe { meos_sys::tgeo_end_value(self.inner()) }).unwrap()
+        gserialized_to_point(unsafe { meos_sys::tgeo_end_value(self.inner()) })
     }
 
     fn value_at_timestamp<Tz: TimeZone>(&self, timestamp: DateTime<Tz>) -> Option<Self::Type> {
@@ -254,7 +324,7 @@ impl Temporal for TGeomPoint {
                 result.as_mut_ptr(),
             );
             if success {
-                Some(gserialized_to_geometry(result.assume_init()).unwrap())
+                Some(gserialized_to_point(result.assume_init()))
             } else {
                 None
             }
@@ -262,8 +332,7 @@ impl Temporal for TGeomPoint {
     }
 
     fn at_value(&self, value: &Self::Type) -> Option<Self::Enum> {
-        let result =
-            unsafe { meos_sys::tpoint_at_value(self.inner(), geometry_to_gserialized(value)) };
+        let result = unsafe { meos_sys::tpoint_at_value(self.inner(), point_like(self, value)) };
         if result.is_null() {
             None
         } else {
@@ -272,7 +341,7 @@ impl Temporal for TGeomPoint {
     }
     fn at_values(&self, values: &[Self::Type]) -> Option<Self::Enum> {
         unsafe {
-            let set = create_set_of_geometries(values);
+            let set = create_set_of_points(self, values);
             let result = meos_sys::temporal_at_values(self.inner(), set);
             if result.is_null() {
                 None
@@ -284,26 +353,26 @@ impl Temporal for TGeomPoint {
 
     fn minus_value(&self, value: Self::Type) -> Self::Enum {
         factory::<Self::Enum>(unsafe {
-            meos_sys::tpoint_minus_value(self.inner(), geometry_to_gserialized(&value))
+            meos_sys::tpoint_minus_value(self.inner(), point_like(self, &value))
         })
     }
 
     fn minus_values(&self, values: &[Self::Type]) -> Self::Enum {
         factory::<Self::Enum>(unsafe {
-            let set = create_set_of_geometries(values);
+            let set = create_set_of_points(self, values);
             meos_sys::temporal_minus_values(self.inner(), set)
         })
     }
 
     fn temporal_equal_value(&self, value: &Self::Type) -> Self::TBoolType {
         Self::TBoolType::from_inner_as_temporal(unsafe {
-            meos_sys::teq_tgeo_geo(self.inner(), geometry_to_gserialized(value))
+            meos_sys::teq_tgeo_geo(self.inner(), point_like(self, value))
         })
     }
 
     fn temporal_not_equal_value(&self, value: &Self::Type) -> Self::TBoolType {
         Self::TBoolType::from_inner_as_temporal(unsafe {
-            meos_sys::tne_tgeo_geo(self.inner(), geometry_to_gserialized(value))
+            meos_sys::tne_tgeo_geo(self.inner(), point_like(self, value))
         })
     }
 }
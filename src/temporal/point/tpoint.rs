@@ -1,11 +1,24 @@
+#[cfg(feature = "geo-types")]
+use super::gserialized::GSerialized;
+#[cfg(feature = "geo-types")]
+use crate::errors::ParseError;
 use crate::temporal::tinstant::TInstant;
 use crate::temporal::JSONCVariant;
 use crate::{
     boxes::STBox,
     factory,
-    temporal::{number::tfloat::TFloat, temporal::Temporal},
+    temporal::{
+        interpolation::TInterpolation,
+        number::tfloat::{TFloat, TFloatInstant, TFloatSequence, TFloatSequenceSet},
+        temporal::Temporal,
+        tsequence::TSequence,
+        tsequence_set::TSequenceSet,
+    },
+    utils::{create_interval, from_meos_array, to_meos_timestamp},
 };
+use chrono::{DateTime, TimeDelta, TimeZone};
 use core::fmt;
+#[cfg(feature = "geos")]
 use geos::{CoordDimensions, Geom, Geometry, WKBWriter};
 use meos_sys::GSERIALIZED;
 use std::{
@@ -13,21 +26,203 @@ use std::{
     ptr, slice,
 };
 
-#[derive(Clone, Copy)]
-pub struct Point(f64, f64, Option<f64>);
+/// Default SRID assigned by `meos` to geographies without an explicit one.
+pub(super) const DEFAULT_GEODETIC_SRID: i32 = 4326;
 
-fn point_to_gserialize(point: Point, geodetic: bool) -> *mut meos_sys::GSERIALIZED {
-    let cstring = CString::new(point.to_string()).unwrap();
-    unsafe {
-        if geodetic {
-            meos_sys::geom_in(cstring.as_ptr().cast_mut(), -1)
+const EWKB_Z_FLAG: u32 = 0x8000_0000;
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// A point value with `x`, `y` and optional `z` coordinates.
+///
+/// This is the base value type of `TGeomPoint` and `TGeogPoint`, and is always
+/// available, regardless of whether the `geos` feature is enabled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point(pub f64, pub f64, pub Option<f64>);
+
+impl Point {
+    /// Returns the `x` coordinate of the point.
+    pub fn x(&self) -> f64 {
+        self.0
+    }
+
+    /// Returns the `y` coordinate of the point.
+    pub fn y(&self) -> f64 {
+        self.1
+    }
+
+    /// Returns the `z` coordinate of the point, if any.
+    pub fn z(&self) -> Option<f64> {
+        self.2
+    }
+
+    /// Parses a point from its (E)WKB representation, returns `None` if the
+    /// bytes don't describe a point.
+    pub fn from_wkb(bytes: &[u8]) -> Option<Self> {
+        let (&order, rest) = bytes.split_first()?;
+        let little_endian = order == 1;
+        let read_word = |b: &[u8]| -> Option<u32> {
+            let b: [u8; 4] = b.get(..4)?.try_into().ok()?;
+            Some(if little_endian {
+                u32::from_le_bytes(b)
+            } else {
+                u32::from_be_bytes(b)
+            })
+        };
+        let read_coord = |b: &[u8]| -> Option<f64> {
+            let b: [u8; 8] = b.get(..8)?.try_into().ok()?;
+            Some(if little_endian {
+                f64::from_le_bytes(b)
+            } else {
+                f64::from_be_bytes(b)
+            })
+        };
+
+        let wkb_type = read_word(rest)?;
+        let mut offset = 4;
+        if wkb_type & EWKB_SRID_FLAG != 0 {
+            offset += 4;
+        }
+        // Both EWKB flags and ISO type codes (1001, 3001) are accepted, M values are ignored
+        let iso_type = wkb_type & 0x0FFF_FFFF;
+        if iso_type % 1000 != 1 {
+            return None;
+        }
+        let has_z = wkb_type & EWKB_Z_FLAG != 0 || iso_type / 1000 == 1 || iso_type / 1000 == 3;
+
+        let x = read_coord(rest.get(offset..)?)?;
+        let y = read_coord(rest.get(offset + 8..)?)?;
+        let z = if has_z {
+            Some(read_coord(rest.get(offset + 16..)?)?)
         } else {
-            meos_sys::geog_in(cstring.as_ptr().cast_mut(), -1)
+            None
+        };
+        Some(Self(x, y, z))
+    }
+
+    /// Returns the little endian WKB representation of the point.
+    pub fn to_wkb(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(29);
+        bytes.push(1);
+        let wkb_type = if self.2.is_some() { 1 | EWKB_Z_FLAG } else { 1 };
+        bytes.extend_from_slice(&wkb_type.to_le_bytes());
+        bytes.extend_from_slice(&self.0.to_le_bytes());
+        bytes.extend_from_slice(&self.1.to_le_bytes());
+        if let Some(z) = self.2 {
+            bytes.extend_from_slice(&z.to_le_bytes());
         }
+        bytes
+    }
+}
+
+impl From<(f64, f64)> for Point {
+    fn from((x, y): (f64, f64)) -> Self {
+        Self(x, y, None)
+    }
+}
+
+impl From<(f64, f64, f64)> for Point {
+    fn from((x, y, z): (f64, f64, f64)) -> Self {
+        Self(x, y, Some(z))
+    }
+}
+
+#[cfg(feature = "geos")]
+impl TryFrom<&Geometry> for Point {
+    type Error = geos::Error;
+
+    fn try_from(geometry: &Geometry) -> Result<Self, Self::Error> {
+        let mut writer = WKBWriter::new()?;
+        writer.set_output_dimension(CoordDimensions::ThreeD);
+        let wkb: Vec<u8> = writer.write_wkb(geometry)?;
+        Self::from_wkb(&wkb)
+            .ok_or_else(|| geos::Error::GenericError("Geometry is not a point".to_owned()))
     }
 }
 
+#[cfg(feature = "geos")]
+impl TryFrom<Point> for Geometry {
+    type Error = geos::Error;
+
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        Geometry::new_from_wkb(&point.to_wkb())
+    }
+}
+
+/// Creates a `meos` point from `point` with the given SRID.
+pub(super) fn point_to_gserialized(point: &Point, srid: i32, geodetic: bool) -> *mut GSERIALIZED {
+    crate::meos_initialize();
+    unsafe {
+        match (geodetic, point.2) {
+            (true, Some(z)) => meos_sys::geogpoint_make3dz(srid, point.0, point.1, z),
+            (true, None) => meos_sys::geogpoint_make2d(srid, point.0, point.1),
+            (false, Some(z)) => meos_sys::geompoint_make3dz(srid, point.0, point.1, z),
+            (false, None) => meos_sys::geompoint_make2d(srid, point.0, point.1),
+        }
+    }
+}
+
+/// Returns the SRID given by `meos` to points created without an explicit one.
+pub(super) const fn default_srid(geodetic: bool) -> i32 {
+    if geodetic {
+        DEFAULT_GEODETIC_SRID
+    } else {
+        0
+    }
+}
+
+/// Creates a `meos` point from `point` with the SRID of `temporal`, so that
+/// both can be used together.
+pub(super) fn point_like<T: TPointTrait<IS_GEODETIC>, const IS_GEODETIC: bool>(
+    temporal: &T,
+    point: &Point,
+) -> *mut GSERIALIZED {
+    point_to_gserialized(point, temporal.srid(), IS_GEODETIC)
+}
+
+pub(super) fn gserialized_to_point(gs: *const GSERIALIZED) -> Point {
+    let mut size = 0;
+    let endian = CString::new("NDR").unwrap();
+    let bytes = unsafe { meos_sys::geo_as_ewkb(gs, endian.as_ptr(), ptr::addr_of_mut!(size)) };
+    let point = Point::from_wkb(unsafe { slice::from_raw_parts(bytes, size) })
+        .expect("Temporal point values must be points");
+    unsafe { libc::free(bytes.cast::<c_void>()) };
+    point
+}
+
+/// Monday, January 3, 2000, the default temporal origin of the tilings in `meos`.
+const DEFAULT_TIME_ORIGIN: meos_sys::TimestampTz = 2 * 24 * 3600 * 1_000_000;
+
+/// Creates the origin of a spatial tiling of `temporal`, with its SRID and dimensionality.
+fn tiling_origin<T: TPointTrait<IS_GEODETIC>, const IS_GEODETIC: bool>(
+    temporal: &T,
+    origin: Option<Point>,
+) -> *mut GSERIALIZED {
+    let mut origin = origin.unwrap_or(Point(0., 0., None));
+    if temporal.has_z() {
+        origin.2.get_or_insert(0.);
+    }
+    point_like(temporal, &origin)
+}
+
+pub(super) fn create_set_of_points<T: TPointTrait<IS_GEODETIC>, const IS_GEODETIC: bool>(
+    temporal: &T,
+    values: &[Point],
+) -> *mut meos_sys::Set {
+    let mut cpoints: Vec<_> = values
+        .iter()
+        .map(|point| point_like(temporal, point))
+        .collect();
+    let set = unsafe { meos_sys::geoset_make(cpoints.as_mut_ptr(), values.len() as i32) };
+    // `geoset_make` copies the points into the set
+    for point in cpoints {
+        unsafe { libc::free(point.cast::<c_void>()) };
+    }
+    set
+}
+
+#[cfg(feature = "geos")]
 pub(super) fn geometry_to_gserialized(geometry: &Geometry) -> *mut GSERIALIZED {
+    crate::meos_initialize();
     let mut writer = WKBWriter::new().expect("Failed to create WKBWriter");
     writer.set_output_dimension(CoordDimensions::ThreeD);
     let wkb: Vec<u8> = writer.write_wkb(geometry).unwrap();
@@ -42,6 +237,7 @@ pub(super) fn geometry_to_gserialized(geometry: &Geometry) -> *mut GSERIALIZED {
     }
 }
 
+#[cfg(feature = "geos")]
 pub(super) fn gserialized_to_geometry(
     gs: *mut meos_sys::GSERIALIZED,
 ) -> Result<Geometry, geos::Error> {
@@ -52,17 +248,40 @@ pub(super) fn gserialized_to_geometry(
     Geometry::new_from_wkb(unsafe { slice::from_raw_parts(bytes, size) })
 }
 
-pub(super) fn create_set_of_geometries(values: &[Geometry]) -> *mut meos_sys::Set {
-    let mut cgeos: Vec<_> = values.iter().map(geometry_to_gserialized).collect();
-    unsafe { meos_sys::geoset_make(cgeos.as_mut_ptr(), values.len() as i32) }
+#[cfg(feature = "geo-types")]
+fn geo_types_to_gserialized(
+    geometry: &geo_types::Geometry,
+    srid: i32,
+    geodetic: bool,
+) -> GSerialized {
+    let gs = GSerialized::from(geometry).with_srid(srid);
+    if geodetic {
+        gs.to_geography()
+    } else {
+        gs
+    }
+}
+
+#[cfg(feature = "geo-types")]
+fn create_set_of_geo_types(
+    geometries: &[geo_types::Geometry],
+    srid: i32,
+    geodetic: bool,
+) -> *mut meos_sys::Set {
+    let values: Vec<_> = geometries
+        .iter()
+        .map(|geometry| geo_types_to_gserialized(geometry, srid, geodetic))
+        .collect();
+    let mut pointers: Vec<_> = values.iter().map(|gs| gs.inner().cast_mut()).collect();
+    unsafe { meos_sys::geoset_make(pointers.as_mut_ptr(), pointers.len() as i32) }
 }
 
 impl fmt::Display for Point {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let Some(z) = self.2 {
-            f.write_fmt(format_args!("Point({}, {}, {})", self.0, self.1, z))
+            f.write_fmt(format_args!("POINT Z({} {} {})", self.0, self.1, z))
         } else {
-            f.write_fmt(format_args!("Point({}, {})", self.0, self.1))
+            f.write_fmt(format_args!("POINT({} {})", self.0, self.1))
         }
     }
 }
@@ -176,6 +395,75 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         factory::<TFloat>(unsafe { meos_sys::tpoint_speed(self.inner()) })
     }
 
+    /// Returns the acceleration of the temporal point, in units of the SRID per second squared.
+    ///
+    /// The speed of a temporal point is constant over each segment, so it is
+    /// taken as the speed at the midpoint of the segment, and the acceleration
+    /// between the midpoints of consecutive segments is their change in speed
+    /// divided by the time between them. The acceleration of a sequence is thus
+    /// defined from the midpoint of its first segment to the midpoint of its
+    /// last segment, except for sequences with a single segment, whose speed
+    /// is constant and whose acceleration is zero over the whole segment.
+    ///
+    /// ## Returns
+    ///
+    /// A stepwise `TFloat`, or `None` if the temporal point does not have
+    /// linear interpolation or has no segment.
+    fn acceleration(&self) -> Option<TFloat> {
+        if self.interpolation() != TInterpolation::Linear {
+            return None;
+        }
+        let sequences: Vec<_> = self
+            .speed()
+            .sequences()
+            .iter()
+            .filter_map(|sequence| {
+                let instants = sequence.instants();
+                let acceleration: Vec<_> = match instants.as_slice() {
+                    [] | [_] => return None,
+                    [start, end] => vec![
+                        TFloatInstant::from_value_and_timestamp(0.0, start.timestamp()),
+                        TFloatInstant::from_value_and_timestamp(0.0, end.timestamp()),
+                    ],
+                    _ => {
+                        // The last instant of a speed sequence repeats the speed of the last segment
+                        let midpoints: Vec<_> = instants
+                            .windows(2)
+                            .map(|segment| {
+                                let (start, end) = (segment[0].timestamp(), segment[1].timestamp());
+                                (segment[0].value(), start + (end - start) / 2)
+                            })
+                            .collect();
+                        let mut acceleration: Vec<_> = midpoints
+                            .windows(2)
+                            .map(|pair| {
+                                let ((start_speed, start), (end_speed, end)) = (pair[0], pair[1]);
+                                #[allow(clippy::cast_precision_loss)]
+                                let seconds = (end - start).num_microseconds()? as f64 / 1e6;
+                                Some(TFloatInstant::from_value_and_timestamp(
+                                    (end_speed - start_speed) / seconds,
+                                    start,
+                                ))
+                            })
+                            .collect::<Option<_>>()?;
+                        let (_, end) = midpoints[midpoints.len() - 1];
+                        let last = acceleration[acceleration.len() - 1].value();
+                        acceleration.push(TFloatInstant::from_value_and_timestamp(last, end));
+                        acceleration
+                    }
+                };
+                Some(TFloatSequence::new(&acceleration, TInterpolation::Stepwise))
+            })
+            .collect();
+        match sequences.len() {
+            0 => None,
+            1 => sequences.into_iter().next().map(TFloat::Sequence),
+            _ => Some(TFloat::SequenceSet(TFloatSequenceSet::new(
+                &sequences, false,
+            ))),
+        }
+    }
+
     /// Returns the x coordinate of the temporal point.
     ///
     /// ## Returns
@@ -259,17 +547,147 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
     /// `tpoint_stboxes`
     fn stboxes(&self) -> Vec<STBox> {
         let mut count = 0;
-        let result = unsafe { meos_sys::tgeo_stboxes(self.inner(), ptr::addr_of_mut!(count)) };
+        unsafe {
+            let boxes = meos_sys::tgeo_stboxes(self.inner(), ptr::addr_of_mut!(count));
+            from_meos_array(boxes, count, |stbox| {
+                STBox::from_inner(meos_sys::stbox_copy(stbox))
+            })
+        }
+    }
 
+    /// Returns up to `box_count` bounding boxes covering the temporal point,
+    /// each spanning a similar number of instants.
+    ///
+    /// ## Arguments
+    ///
+    /// * `box_count` - Maximum number of boxes.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `tgeo_split_n_stboxes`
+    fn split_n_stboxes(&self, box_count: i32) -> Vec<STBox> {
+        let mut count = 0;
         unsafe {
-            std::slice::from_raw_parts(result, count as usize)
-                .iter()
-                .map(|&stbox| {
-                    let mut boxed_stbox = Box::new(stbox);
-                    let ptr: *mut meos_sys::STBox = &raw mut *boxed_stbox;
-                    STBox::from_inner(ptr)
-                })
-                .collect()
+            let boxes =
+                meos_sys::tgeo_split_n_stboxes(self.inner(), box_count, ptr::addr_of_mut!(count));
+            from_meos_array(boxes, count, |stbox| {
+                STBox::from_inner(meos_sys::stbox_copy(stbox))
+            })
+        }
+    }
+
+    /// Returns the bounding boxes covering the temporal point, each spanning
+    /// `elem_count` consecutive instants.
+    ///
+    /// ## Arguments
+    ///
+    /// * `elem_count` - Number of instants per box.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `tgeo_split_each_n_stboxes`
+    fn split_each_n_stboxes(&self, elem_count: i32) -> Vec<STBox> {
+        let mut count = 0;
+        unsafe {
+            let boxes = meos_sys::tgeo_split_each_n_stboxes(
+                self.inner(),
+                elem_count,
+                ptr::addr_of_mut!(count),
+            );
+            from_meos_array(boxes, count, |stbox| {
+                STBox::from_inner(meos_sys::stbox_copy(stbox))
+            })
+        }
+    }
+
+    /// Returns the bounding boxes of the temporal point within each spatial tile it crosses.
+    ///
+    /// ## Arguments
+    ///
+    /// * `xsize`, `ysize`, `zsize` - Size of the tiles in each dimension.
+    /// * `origin` - Origin of the tiling, (0, 0, 0) if not provided.
+    /// * `bitmatrix` - Whether to use a bit matrix to speed up the computation.
+    /// * `border_inc` - Whether the upper border of the tiles is inclusive.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `tgeo_space_boxes`
+    fn space_boxes(
+        &self,
+        xsize: f64,
+        ysize: f64,
+        zsize: f64,
+        origin: Option<Point>,
+        bitmatrix: bool,
+        border_inc: bool,
+    ) -> Vec<STBox> {
+        let origin = tiling_origin(self, origin);
+        let mut count = 0;
+        unsafe {
+            let boxes = meos_sys::tgeo_space_boxes(
+                self.inner(),
+                xsize,
+                ysize,
+                zsize,
+                origin,
+                bitmatrix,
+                border_inc,
+                ptr::addr_of_mut!(count),
+            );
+            libc::free(origin.cast());
+            from_meos_array(boxes, count, |stbox| {
+                STBox::from_inner(meos_sys::stbox_copy(stbox))
+            })
+        }
+    }
+
+    /// Returns the bounding boxes of the temporal point within each spatiotemporal tile it crosses.
+    ///
+    /// ## Arguments
+    ///
+    /// * `xsize`, `ysize`, `zsize` - Size of the tiles in each spatial dimension.
+    /// * `duration` - Duration of the tiles.
+    /// * `origin` - Spatial origin of the tiling, (0, 0, 0) if not provided.
+    /// * `time_origin` - Temporal origin of the tiling, Monday, January 3, 2000 if not provided.
+    /// * `bitmatrix` - Whether to use a bit matrix to speed up the computation.
+    /// * `border_inc` - Whether the upper border of the tiles is inclusive.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `tgeo_space_time_boxes`
+    #[allow(clippy::too_many_arguments)]
+    fn space_time_boxes<Tz: TimeZone>(
+        &self,
+        xsize: f64,
+        ysize: f64,
+        zsize: f64,
+        duration: TimeDelta,
+        origin: Option<Point>,
+        time_origin: Option<DateTime<Tz>>,
+        bitmatrix: bool,
+        border_inc: bool,
+    ) -> Vec<STBox> {
+        let origin = tiling_origin(self, origin);
+        let duration = create_interval(duration);
+        let time_origin = time_origin.map_or(DEFAULT_TIME_ORIGIN, |t| to_meos_timestamp(&t));
+        let mut count = 0;
+        unsafe {
+            let boxes = meos_sys::tgeo_space_time_boxes(
+                self.inner(),
+                xsize,
+                ysize,
+                zsize,
+                ptr::addr_of!(duration),
+                origin,
+                time_origin,
+                bitmatrix,
+                border_inc,
+                ptr::addr_of_mut!(count),
+            );
+            libc::free(origin.cast());
+            from_meos_array(boxes, count, |stbox| {
+                STBox::from_inner(meos_sys::stbox_copy(stbox))
+            })
         }
     }
 
@@ -303,6 +721,7 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         factory::<TFloat>(unsafe { meos_sys::bearing_tpoint_tpoint(self.inner(), other.inner()) })
     }
 
+    #[cfg(feature = "geos")]
     /// Returns the temporal bearing between the temporal point and another point.
     ///
     /// ## Arguments
@@ -357,6 +776,7 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         }
     }
 
+    #[cfg(feature = "geos")]
     /// Returns the time-weighted centroid of the temporal point.
     ///
     /// ## Arguments
@@ -375,6 +795,7 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         gserialized_to_geometry(gs)
     }
 
+    #[cfg(feature = "geos")]
     /// Returns the trajectory of the temporal point as a geos geometry.
     ///
     /// ## Arguments
@@ -490,10 +911,11 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
     ///     `tpoint_at_value`, `tpoint_at_stbox`, `temporal_at_values`,
     ///     `temporal_at_timestamp`, `temporal_at_tstzset`, `temporal_at_tstzspan`, `temporal_at_tstzspanset`
     fn at_point(&self, point: Point) -> Self::Enum {
-        let geo = point_to_gserialize(point, IS_GEODETIC);
+        let geo = point_like(self, &point);
         factory::<Self::Enum>(unsafe { meos_sys::tpoint_at_value(self.inner(), geo) })
     }
 
+    #[cfg(feature = "geos")]
     /// Returns a new temporal object with the values of `self` restricted to `other`.
     ///
     /// Args:
@@ -510,6 +932,7 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         factory::<Self::Enum>(unsafe { meos_sys::tpoint_at_value(self.inner(), geo) })
     }
 
+    #[cfg(feature = "geos")]
     /// Returns a new temporal object with the values of `self` restricted to `other`.
     ///
     /// Args:
@@ -553,10 +976,11 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
     ///     `tpoint_minus_value`, `tpoint_minus_stbox`, `temporal_minus_values`,
     ///     `temporal_minus_timestamp`, `temporal_minus_tstzset`, `temporal_minus_tstzspan`, `temporal_minus_tstzspanset`
     fn minus_point(&self, point: Point) -> Self::Enum {
-        let geo = point_to_gserialize(point, IS_GEODETIC);
+        let geo = point_like(self, &point);
         factory::<Self::Enum>(unsafe { meos_sys::tpoint_minus_value(self.inner(), geo) })
     }
 
+    #[cfg(feature = "geos")]
     /// Returns a new temporal object with the values of `self` restricted to the complement of `other`.
     ///
     /// Args:
@@ -573,6 +997,7 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         factory::<Self::Enum>(unsafe { meos_sys::tpoint_minus_value(self.inner(), geo) })
     }
 
+    #[cfg(feature = "geos")]
     /// Returns a new temporal object with the values of `self` restricted to the complement of `other`.
     ///
     /// Args:
@@ -725,6 +1150,7 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         }
     }
 
+    #[cfg(feature = "geos")]
     /// Returns a new temporal boolean indicating whether the temporal point is contained by `container`.
     ///
     /// # Arguments
@@ -745,6 +1171,7 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         })
     }
 
+    #[cfg(feature = "geos")]
     /// Returns a new temporal boolean indicating whether the temporal point intersects `geometry`.
     ///
     /// # Arguments
@@ -785,6 +1212,7 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         })
     }
 
+    #[cfg(feature = "geos")]
     /// Returns a new temporal boolean indicating whether the temporal point is within `distance` of `geometry`.
     ///
     /// # Arguments
@@ -806,6 +1234,7 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         })
     }
 
+    #[cfg(feature = "geos")]
     /// Returns a new temporal boolean indicating whether the temporal point intersects `geometry`.
     ///
     /// # Arguments
@@ -826,6 +1255,7 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         })
     }
 
+    #[cfg(feature = "geos")]
     /// Returns a new temporal boolean indicating whether the temporal point touches `other`.
     ///
     /// # Arguments
@@ -877,7 +1307,7 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
     ///
     /// * `distance_tgeo_point`, `distance_tgeo_tgeo`
     fn distance_to_point(&self, point: Point) -> TFloat {
-        let point = point_to_gserialize(point, IS_GEODETIC);
+        let point = point_like(self, &point);
         factory::<TFloat>(unsafe { meos_sys::tdistance_tgeo_geo(self.inner(), point) })
     }
 
@@ -898,6 +1328,7 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         unsafe { meos_sys::nad_tgeo_tgeo(self.inner(), other.inner()) }
     }
 
+    #[cfg(feature = "geos")]
     /// Returns the nearest approach distance between the temporal point and `other`.
     ///
     /// # Arguments
@@ -933,6 +1364,7 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         Self::TI::from_inner(unsafe { meos_sys::nai_tgeo_tgeo(self.inner(), other.inner()) })
     }
 
+    #[cfg(feature = "geos")]
     /// Returns the nearest approach instant between the temporal point and `other`.
     ///
     /// # Arguments
@@ -951,6 +1383,7 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         Self::TI::from_inner(unsafe { meos_sys::nai_tgeo_geo(self.inner(), geo) })
     }
 
+    #[cfg(feature = "geos")]
     /// Returns the shortest line between the temporal point and `other`.
     ///
     /// # Arguments
@@ -969,6 +1402,7 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         gserialized_to_geometry(gs)
     }
 
+    #[cfg(feature = "geos")]
     /// Returns the shortest line between the temporal point and `other`.
     ///
     /// # Arguments
@@ -988,6 +1422,233 @@ pub trait TPointTrait<const IS_GEODETIC: bool>: Temporal {
         gserialized_to_geometry(gs)
     }
 
+    // ------------------------- geo-types -------------------------------------
+
+    /// Returns the temporal bearing between the temporal point and a `geo_types` point.
+    ///
+    /// ## Arguments
+    ///
+    /// * `geometry` - The point to check the bearing to.
+    ///
+    /// ## Returns
+    ///
+    /// A `TFloat` indicating the temporal bearing between the temporal point and `geometry`.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `bearing_tpoint_point`
+    #[cfg(feature = "geo-types")]
+    fn bearing_geo_types(&self, geometry: &geo_types::Geometry) -> TFloat {
+        let geo = geo_types_to_gserialized(geometry, self.srid(), IS_GEODETIC);
+        factory::<TFloat>(unsafe {
+            meos_sys::bearing_tpoint_point(self.inner(), geo.inner(), false)
+        })
+    }
+
+    /// Returns the time-weighted centroid of the temporal point as a `geo_types` point.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `tpoint_twcentroid`
+    #[cfg(feature = "geo-types")]
+    fn time_weighted_centroid_geo_types(&self) -> Result<geo_types::Point, ParseError> {
+        let gs = GSerialized::from_inner(unsafe { meos_sys::tpoint_twcentroid(self.inner()) });
+        geo_types::Point::try_from(gs)
+    }
+
+    /// Returns the trajectory of the temporal point as a `geo_types` geometry.
+    ///
+    /// ## Arguments
+    ///
+    /// * `unary_union` - True when the `ST_UnaryUnion` function is applied to
+    ///   the result to remove redundant geometry components.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `tpoint_trajectory`
+    #[cfg(feature = "geo-types")]
+    fn trajectory_geo_types(&self, unary_union: bool) -> Result<geo_types::Geometry, ParseError> {
+        let gs = GSerialized::from_inner(unsafe {
+            meos_sys::tpoint_trajectory(self.inner(), unary_union)
+        });
+        geo_types::Geometry::try_from(gs)
+    }
+
+    /// Returns a new temporal object with the values of `self` restricted to a `geo_types` geometry.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `tpoint_at_value`
+    #[cfg(feature = "geo-types")]
+    fn at_geo_types(&self, geometry: &geo_types::Geometry) -> Self::Enum {
+        let geo = geo_types_to_gserialized(geometry, self.srid(), IS_GEODETIC);
+        factory::<Self::Enum>(unsafe {
+            meos_sys::tpoint_at_value(self.inner(), geo.inner().cast_mut())
+        })
+    }
+
+    /// Returns a new temporal object with the values of `self` restricted to a set of `geo_types` geometries.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `temporal_at_values`
+    #[cfg(feature = "geo-types")]
+    fn at_geo_types_geometries(&self, geometries: &[geo_types::Geometry]) -> Self::Enum {
+        let geoset = create_set_of_geo_types(geometries, self.srid(), IS_GEODETIC);
+        factory::<Self::Enum>(unsafe { meos_sys::temporal_at_values(self.inner(), geoset) })
+    }
+
+    /// Returns a new temporal object with the values of `self` restricted to the complement of a `geo_types` geometry.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `tpoint_minus_value`
+    #[cfg(feature = "geo-types")]
+    fn minus_geo_types(&self, geometry: &geo_types::Geometry) -> Self::Enum {
+        let geo = geo_types_to_gserialized(geometry, self.srid(), IS_GEODETIC);
+        factory::<Self::Enum>(unsafe {
+            meos_sys::tpoint_minus_value(self.inner(), geo.inner().cast_mut())
+        })
+    }
+
+    /// Returns a new temporal object with the values of `self` restricted to the complement of a set of `geo_types` geometries.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `temporal_minus_values`
+    #[cfg(feature = "geo-types")]
+    fn minus_geo_types_geometries(&self, geometries: &[geo_types::Geometry]) -> Self::Enum {
+        let geoset = create_set_of_geo_types(geometries, self.srid(), IS_GEODETIC);
+        factory::<Self::Enum>(unsafe { meos_sys::temporal_minus_values(self.inner(), geoset) })
+    }
+
+    /// Returns a new temporal boolean indicating whether the temporal point is contained by a `geo_types` geometry.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `tcontains_geo_tgeo`
+    #[cfg(feature = "geo-types")]
+    fn is_spatially_contained_in_geo_types(
+        &self,
+        container: &geo_types::Geometry,
+    ) -> Self::TBoolType {
+        let geo = geo_types_to_gserialized(container, self.srid(), IS_GEODETIC);
+        Self::TBoolType::from_inner_as_temporal(unsafe {
+            meos_sys::tcontains_geo_tgeo(geo.inner(), self.inner(), false, false)
+        })
+    }
+
+    /// Returns a new temporal boolean indicating whether the temporal point is disjoint to a `geo_types` geometry.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `tdisjoint_tgeo_geo`
+    #[cfg(feature = "geo-types")]
+    fn is_disjoint_to_geo_types(&self, geometry: &geo_types::Geometry) -> Self::TBoolType {
+        let geo = geo_types_to_gserialized(geometry, self.srid(), IS_GEODETIC);
+        Self::TBoolType::from_inner_as_temporal(unsafe {
+            meos_sys::tdisjoint_tgeo_geo(self.inner(), geo.inner(), false, false)
+        })
+    }
+
+    /// Returns a new temporal boolean indicating whether the temporal point is within `distance` of a `geo_types` geometry.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `tdwithin_tgeo_geo`
+    #[cfg(feature = "geo-types")]
+    fn within_distance_of_geo_types(
+        &self,
+        geometry: &geo_types::Geometry,
+        distance: f64,
+    ) -> Self::TBoolType {
+        let geo = geo_types_to_gserialized(geometry, self.srid(), IS_GEODETIC);
+        Self::TBoolType::from_inner_as_temporal(unsafe {
+            meos_sys::tdwithin_tgeo_geo(self.inner(), geo.inner(), distance, false, false)
+        })
+    }
+
+    /// Returns a new temporal boolean indicating whether the temporal point intersects a `geo_types` geometry.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `tintersects_tgeo_geo`
+    #[cfg(feature = "geo-types")]
+    fn intersects_geo_types(&self, geometry: &geo_types::Geometry) -> Self::TBoolType {
+        let geo = geo_types_to_gserialized(geometry, self.srid(), IS_GEODETIC);
+        Self::TBoolType::from_inner_as_temporal(unsafe {
+            meos_sys::tintersects_tgeo_geo(self.inner(), geo.inner(), false, false)
+        })
+    }
+
+    /// Returns a new temporal boolean indicating whether the temporal point touches a `geo_types` geometry.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `ttouches_tgeo_geo`
+    #[cfg(feature = "geo-types")]
+    fn touches_geo_types(&self, geometry: &geo_types::Geometry) -> Self::TBoolType {
+        let geo = geo_types_to_gserialized(geometry, self.srid(), IS_GEODETIC);
+        Self::TBoolType::from_inner_as_temporal(unsafe {
+            meos_sys::ttouches_tgeo_geo(self.inner(), geo.inner(), false, false)
+        })
+    }
+
+    /// Returns the nearest approach distance between the temporal point and a `geo_types` geometry.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `nad_tgeo_geo`
+    #[cfg(feature = "geo-types")]
+    fn nearest_approach_distance_to_geo_types(&self, geometry: &geo_types::Geometry) -> f64 {
+        let geo = geo_types_to_gserialized(geometry, self.srid(), IS_GEODETIC);
+        unsafe { meos_sys::nad_tgeo_geo(self.inner(), geo.inner()) }
+    }
+
+    /// Returns the nearest approach instant between the temporal point and a `geo_types` geometry.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `nai_tgeo_geo`
+    #[cfg(feature = "geo-types")]
+    fn nearest_approach_instant_to_geo_types(&self, geometry: &geo_types::Geometry) -> Self::TI {
+        let geo = geo_types_to_gserialized(geometry, self.srid(), IS_GEODETIC);
+        Self::TI::from_inner(unsafe { meos_sys::nai_tgeo_geo(self.inner(), geo.inner()) })
+    }
+
+    /// Returns the shortest line between the temporal point and `other` as a `geo_types` geometry.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `shortestline_tgeo_tgeo`
+    #[cfg(feature = "geo-types")]
+    fn shortest_line_geo_types(
+        &self,
+        other: &Self::Enum,
+    ) -> Result<geo_types::Geometry, ParseError> {
+        let gs = GSerialized::from_inner(unsafe {
+            meos_sys::shortestline_tgeo_tgeo(self.inner(), other.inner())
+        });
+        geo_types::Geometry::try_from(gs)
+    }
+
+    /// Returns the shortest line between the temporal point and a `geo_types` geometry.
+    ///
+    /// ## MEOS Functions
+    ///
+    /// `shortestline_tgeo_geo`
+    #[cfg(feature = "geo-types")]
+    fn shortest_line_to_geo_types(
+        &self,
+        geometry: &geo_types::Geometry,
+    ) -> Result<geo_types::Geometry, ParseError> {
+        let geo = geo_types_to_gserialized(geometry, self.srid(), IS_GEODETIC);
+        let gs = GSerialized::from_inner(unsafe {
+            meos_sys::shortestline_tgeo_geo(self.inner(), geo.inner())
+        });
+        geo_types::Geometry::try_from(gs)
+    }
+
     // /// Split the temporal point into segments following the tiling of the bounding box.
     // ///
     // /// # Arguments
@@ -1133,9 +1794,9 @@ macro_rules! impl_tpoint_traits {
     ($type:ty, $temporal_type:ident, $is_geodetic:expr, $tpoint_type:ident) => {
         paste::paste! {
             impl Collection for $type {
-                impl_collection!(tspatial, Geometry);
+                impl_collection!(tspatial, Point);
                 fn contains(&self, element: &Self::Type) -> bool {
-                    unsafe { meos_sys::contains_tspatial_stbox(self.inner(), meos_sys::geo_to_stbox(geometry_to_gserialized(element))) }
+                    unsafe { meos_sys::contains_tspatial_stbox(self.inner(), meos_sys::geo_to_stbox(point_like(self, element))) }
                 }
             }
 
@@ -1155,7 +1816,7 @@ macro_rules! impl_tpoint_traits {
                 type Enum = [<T $tpoint_type Point>];
                 type TBoolType = [<TBool $temporal_type>];
 
-                impl_always_and_ever_value_equality_functions!(geo, geometry_to_gserialized);
+                impl_always_and_ever_value_equality_functions!(geo, with_self point_like);
                 fn from_inner_as_temporal(inner: *mut meos_sys::Temporal) -> Self {
                     Self {
                         #[allow(clippy::cast_ptr_alignment)]
@@ -1178,18 +1839,17 @@ macro_rules! impl_tpoint_traits {
 
                         std::slice::from_raw_parts(values, count as usize)
                             .into_iter()
-                            .map(|&gs| gserialized_to_geometry(gs))
-                            .map(Result::unwrap)
+                            .map(|&gs| gserialized_to_point(gs))
                             .collect()
                     }
                 }
 
                 fn start_value(&self) -> Self::Type {
-                    gserialized_to_geometry(unsafe { meos_sys::tgeo_start_value(self.inner()) }).unwrap()
+                    gserialized_to_point(unsafe { meos_sys::tgeo_start_value(self.inner()) })
                 }
 
                 fn end_value(&self) -> Self::Type {
-                    gserialized_to_geometry(unsafe { meos_sys::tgeo_end_value(self.inner()) }).unwrap()
+                    gserialized_to_point(unsafe { meos_sys::tgeo_end_value(self.inner()) })
                 }
 
                 fn value_at_timestamp<Tz: TimeZone>(
@@ -1205,7 +1865,7 @@ macro_rules! impl_tpoint_traits {
                             result.as_mut_ptr(),
                         );
                         if success {
-                            Some(gserialized_to_geometry(result.assume_init()).unwrap())
+                            Some(gserialized_to_point(result.assume_init()))
                         } else {
                             None
                         }
@@ -1213,7 +1873,7 @@ macro_rules! impl_tpoint_traits {
                 }
 
                 fn at_value(&self, value: &Self::Type) -> Option<Self::Enum> {
-                    let result = unsafe { meos_sys::tpoint_at_value(self.inner(), geometry_to_gserialized(value)) };
+                    let result = unsafe { meos_sys::tpoint_at_value(self.inner(), point_like(self, value)) };
                     if !result.is_null() {
                         Some(factory::<Self::Enum>(result))
                     } else {
@@ -1222,8 +1882,7 @@ macro_rules! impl_tpoint_traits {
                 }
                 fn at_values(&self, values: &[Self::Type]) -> Option<Self::Enum> {
                     unsafe {
-                        let mut cgeos: Vec<_> = values.into_iter().map(|geo| geometry_to_gserialized(&geo)).collect();
-                        let set = meos_sys::geoset_make(cgeos.as_mut_ptr(), values.len() as i32);
+                        let set = create_set_of_points(self, values);
                         let result = meos_sys::temporal_at_values(self.inner(), set);
                         if !result.is_null() {
                             Some(factory::<Self::Enum>(result))
@@ -1235,27 +1894,26 @@ macro_rules! impl_tpoint_traits {
 
                 fn minus_value(&self, value: Self::Type) -> Self::Enum {
                     factory::<Self::Enum>(unsafe {
-                        meos_sys::tpoint_minus_value(self.inner(), geometry_to_gserialized(&value))
+                        meos_sys::tpoint_minus_value(self.inner(), point_like(self, &value))
                     })
                 }
 
                 fn minus_values(&self, values: &[Self::Type]) -> Self::Enum {
                     factory::<Self::Enum>(unsafe {
-                        let mut cgeos: Vec<_> = values.into_iter().map(|geo| geometry_to_gserialized(&geo)).collect();
-                        let set = meos_sys::geoset_make(cgeos.as_mut_ptr(), values.len() as i32);
+                        let set = create_set_of_points(self, values);
                         meos_sys::temporal_minus_values(self.inner(), set)
                     })
                 }
 
                 fn temporal_equal_value(&self, value: &Self::Type) -> Self::TBoolType {
                     Self::TBoolType::from_inner_as_temporal(unsafe {
-                        meos_sys::teq_tgeo_geo(self.inner(), geometry_to_gserialized(value))
+                        meos_sys::teq_tgeo_geo(self.inner(), point_like(self, value))
                     })
                 }
 
                 fn temporal_not_equal_value(&self, value: &Self::Type) -> Self::TBoolType {
                     Self::TBoolType::from_inner_as_temporal(unsafe {
-                        meos_sys::tne_tgeo_geo(self.inner(), geometry_to_gserialized(value))
+                        meos_sys::tne_tgeo_geo(self.inner(), point_like(self, value))
                     })
                 }
             }
@@ -0,0 +1,218 @@
+use std::{collections::HashMap, hash::Hash};
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::temporal::{
+    interpolation::TInterpolation, temporal::Temporal, tinstant::TInstant, tsequence::TSequence,
+    tsequence_set::TSequenceSet,
+};
+
+use super::tgeompoint::{TGeomPoint, TGeomPointInstant, TGeomPointSequence, TGeomPointSequenceSet};
+
+/// Incremental assembler of the trips of many entities from a stream of instants.
+///
+/// Each entity has an open linear sequence, to which instants are appended
+/// with [`Temporal::append_instant`]. When the time or the distance to the
+/// previous instant exceeds `max_time` or `max_dist`, the open sequence is
+/// closed and emitted by [`Self::push`], and a new one starts with the
+/// instant, so only the open sequences are held in memory. Open sequences are
+/// also emitted once they reach `max_instants`, once they have been idle for
+/// longer than `max_time`, or when finishing.
+///
+/// ## Example
+/// ```
+/// # use chrono::{TimeDelta, TimeZone, Utc};
+/// # use meos::{meos_initialize, Point, TGeomPointInstant, TInstant, TripAssembler};
+/// # meos_initialize();
+/// let mut assembler = TripAssembler::new().max_time(TimeDelta::minutes(10));
+/// let t = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+/// assembler.push("a", TGeomPointInstant::from_value_and_timestamp(Point(0., 0., None), t));
+/// let trips = assembler.finish();
+/// ```
+#[derive(Debug)]
+pub struct TripAssembler<K> {
+    trips: HashMap<K, TGeomPointSequence>,
+    max_dist: Option<f64>,
+    max_time: Option<TimeDelta>,
+    max_instants: Option<usize>,
+    discarded_instants: usize,
+}
+
+impl<K: Eq + Hash> Default for TripAssembler<K> {
+    fn default() -> Self {
+        Self {
+            trips: HashMap::new(),
+            max_dist: None,
+            max_time: None,
+            max_instants: None,
+            discarded_instants: 0,
+        }
+    }
+}
+
+impl<K: Eq + Hash> TripAssembler<K> {
+    /// Creates an assembler that never splits nor emits trips before finishing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum distance between consecutive instants of a sequence.
+    pub fn max_dist(mut self, max_dist: f64) -> Self {
+        self.max_dist = Some(max_dist);
+        self
+    }
+
+    /// Sets the maximum time between consecutive instants of a sequence.
+    pub fn max_time(mut self, max_time: TimeDelta) -> Self {
+        self.max_time = Some(max_time);
+        self
+    }
+
+    /// Sets the number of instants after which a trip is emitted by [`Self::push`].
+    ///
+    /// The next trip of the entity starts with the last instant of the emitted
+    /// one, so that consecutive trips are continuous.
+    pub fn max_instants(mut self, max_instants: usize) -> Self {
+        self.max_instants = Some(max_instants);
+        self
+    }
+
+    /// Appends `instant` to the trip of `id`.
+    ///
+    /// Instants that are not after the last instant of the trip are discarded.
+    ///
+    /// ## Returns
+    /// The trip of `id` if it was closed by a gap before `instant` or if it has
+    /// reached `max_instants`, `None` otherwise.
+    pub fn push(&mut self, id: K, instant: TGeomPointInstant) -> Option<TGeomPointSequenceSet> {
+        let Some(open) = self.trips.remove(&id) else {
+            let open = TGeomPointSequence::new(&[instant], TInterpolation::Linear);
+            self.trips.insert(id, open);
+            return None;
+        };
+        if instant.timestamp() <= open.end_timestamp() {
+            self.discarded_instants += 1;
+            self.trips.insert(id, open);
+            return None;
+        }
+        let (closed, open) = match open.append_instant(
+            instant,
+            TInterpolation::Linear,
+            self.max_dist,
+            self.max_time,
+        ) {
+            TGeomPoint::Sequence(open) => (None, open),
+            TGeomPoint::SequenceSet(sequence_set) => {
+                let mut sequences = sequence_set.sequences();
+                let open = sequences
+                    .pop()
+                    .expect("a split sequence has at least two sequences");
+                (Some(TGeomPointSequenceSet::new(&sequences, false)), open)
+            }
+            TGeomPoint::Instant(_) => unreachable!("appending to a sequence returns a sequence"),
+        };
+        if closed.is_none()
+            && self
+                .max_instants
+                .is_some_and(|max_instants| open.num_instants() as usize >= max_instants)
+        {
+            let next = TGeomPointSequence::new(&[open.end_instant()], TInterpolation::Linear);
+            self.trips.insert(id, next);
+            return Some(Self::emit(open));
+        }
+        self.trips.insert(id, open);
+        closed
+    }
+
+    /// Removes and returns the trips whose last instant is more than `max_time`
+    /// before `now`, which can no longer be extended. Does nothing if `max_time`
+    /// is not set.
+    pub fn flush_idle(&mut self, now: DateTime<Utc>) -> Vec<(K, TGeomPointSequenceSet)> {
+        let Some(max_time) = self.max_time else {
+            return Vec::new();
+        };
+        let (idle, open): (HashMap<_, _>, HashMap<_, _>) = std::mem::take(&mut self.trips)
+            .into_iter()
+            .partition(|(_, open)| open.end_timestamp() + max_time < now);
+        self.trips = open;
+        idle.into_iter()
+            .map(|(id, open)| (id, Self::emit(open)))
+            .collect()
+    }
+
+    /// Returns all the trips being assembled.
+    pub fn finish(mut self) -> Vec<(K, TGeomPointSequenceSet)> {
+        std::mem::take(&mut self.trips)
+            .into_iter()
+            .map(|(id, open)| (id, Self::emit(open)))
+            .collect()
+    }
+
+    fn emit(open: TGeomPointSequence) -> TGeomPointSequenceSet {
+        TGeomPointSequenceSet::new(&[open], false)
+    }
+
+    /// Returns the number of trips being assembled.
+    pub fn len(&self) -> usize {
+        self.trips.len()
+    }
+
+    /// Returns whether no trip is being assembled.
+    pub fn is_empty(&self) -> bool {
+        self.trips.is_empty()
+    }
+
+    /// Returns the number of instants discarded because they were out of order.
+    pub fn discarded_instants(&self) -> usize {
+        self.discarded_instants
+    }
+}
+
+#[cfg(test)]
+#[serial_test::serial]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::{meos_initialize, meos_initialize_timezone, Point, SpanSet, Temporal};
+
+    #[test]
+    fn assemble_trips() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let t = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let instant = |x, minutes| {
+            TGeomPointInstant::from_value_and_timestamp(
+                Point(x, 0., None),
+                t + TimeDelta::minutes(minutes),
+            )
+        };
+        let mut assembler = TripAssembler::new().max_time(TimeDelta::minutes(10));
+        assert!(assembler.push(1, instant(0., 0)).is_none());
+        assembler.push(1, instant(1., 1));
+        assert!(assembler.push(1, instant(2., 1)).is_none());
+        let closed = assembler.push(1, instant(3., 30)).unwrap();
+        assert_eq!(closed.num_instants(), 2);
+        assert_eq!(closed.end_timestamp(), t + TimeDelta::minutes(1));
+        assembler.push(2, instant(0., 30));
+        assert_eq!(assembler.discarded_instants(), 1);
+
+        let idle = assembler.flush_idle(t + TimeDelta::minutes(35));
+        assert!(idle.is_empty());
+        let idle = assembler.flush_idle(t + TimeDelta::minutes(45));
+        assert_eq!(idle.len(), 2);
+        let (_, trip) = idle.into_iter().find(|(id, _)| *id == 1).unwrap();
+        assert_eq!(trip.time().num_spans(), 1);
+        assert_eq!(trip.num_instants(), 1);
+        assert!(assembler.is_empty());
+
+        let mut assembler = TripAssembler::new().max_instants(2);
+        assert!(assembler.push("a", instant(0., 0)).is_none());
+        let first = assembler.push("a", instant(1., 1)).unwrap();
+        assert_eq!(first.num_instants(), 2);
+        assert_eq!(assembler.len(), 1);
+        let (_, second) = assembler.finish().pop().unwrap();
+        assert_eq!(second.start_timestamp(), first.end_timestamp());
+        assert_eq!(second.start_value(), first.end_value());
+    }
+}
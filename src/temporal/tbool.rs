@@ -7,7 +7,7 @@ use std::{
     str::FromStr,
 };
 
-use chrono::{DateTime, TimeZone};
+use chrono::{DateTime, TimeDelta, TimeZone};
 
 use crate::{
     collections::{
@@ -18,13 +18,14 @@ use crate::{
     factory, impl_from_str,
     temporal::{
         temporal::{
-            impl_always_and_ever_value_equality_functions, impl_simple_traits_for_temporal,
-            Temporal,
+            impl_always_and_ever_value_equality_functions, impl_from_base_temporal,
+            impl_simple_traits_for_temporal, Temporal,
         },
         tinstant::TInstant,
         tsequence::TSequence,
         tsequence_set::TSequenceSet,
     },
+    try_factory,
     utils::to_meos_timestamp,
     MeosEnum,
 };
@@ -196,6 +197,8 @@ pub enum TBool {
 
 impl_from_str!(TBool);
 
+impl_from_base_temporal!(TBool, bool, "tbool_from_base_temp");
+
 impl MeosEnum for TBool {
     fn from_instant(inner: *mut meos_sys::TInstant) -> Self {
         Self::Instant(TBoolInstant::from_inner(inner))
@@ -209,9 +212,9 @@ impl MeosEnum for TBool {
         Self::SequenceSet(TBoolSequenceSet::from_inner(inner))
     }
 
-    fn from_mfjson(mfjson: &str) -> Self {
-        let cstr = CString::new(mfjson).unwrap();
-        factory::<Self>(unsafe { meos_sys::tbool_from_mfjson(cstr.as_ptr()) })
+    fn try_from_mfjson(mfjson: &str) -> Result<Self, ParseError> {
+        let cstr = CString::new(mfjson).map_err(|_| ParseError)?;
+        try_factory::<Self>(|| unsafe { meos_sys::tbool_from_mfjson(cstr.as_ptr()) })
     }
 }
 
@@ -248,6 +251,41 @@ pub trait TBoolTrait:
     fn at_false(&self) -> Option<Self::Enum> {
         self.at_value(&false)
     }
+
+    /// Returns the time during which the temporal boolean is true.
+    ///
+    /// ## Returns
+    /// A `TsTzSpanSet`, or `None` if the value is never true.
+    #[doc(alias = "tbool_when_true")]
+    fn when_true(&self) -> Option<TsTzSpanSet> {
+        let result = unsafe { meos_sys::tbool_when_true(self.inner()) };
+        if result.is_null() {
+            None
+        } else {
+            Some(TsTzSpanSet::from_inner(result))
+        }
+    }
+
+    /// Returns the time during which the temporal boolean is false.
+    ///
+    /// ## Returns
+    /// A `TsTzSpanSet`, or `None` if the value is never false.
+    #[doc(alias = "tbool_when_true")]
+    fn when_false(&self) -> Option<TsTzSpanSet> {
+        self.temporal_not().when_true()
+    }
+
+    /// Returns the total duration during which the temporal boolean is true.
+    fn duration_true(&self) -> TimeDelta {
+        self.when_true()
+            .map_or_else(TimeDelta::zero, |time| time.duration(false))
+    }
+
+    /// Returns the total duration during which the temporal boolean is false.
+    fn duration_false(&self) -> TimeDelta {
+        self.when_false()
+            .map_or_else(TimeDelta::zero, |time| time.duration(false))
+    }
 }
 
 pub struct TBoolInstant {
@@ -266,6 +304,7 @@ impl TInstant for TBoolInstant {
     }
 
     fn from_value_and_timestamp<Tz: TimeZone>(value: Self::Type, timestamp: DateTime<Tz>) -> Self {
+        crate::meos_initialize();
         Self::from_inner(unsafe { meos_sys::tboolinst_make(value, to_meos_timestamp(&timestamp)) })
     }
 }
@@ -580,4 +619,21 @@ mod tests {
             format!("SequenceSet({})", string.to_owned())
         );
     }
+
+    #[test]
+    fn when_true_and_false() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let inside: TBool =
+            "[t@2018-01-01 08:00:00+00, f@2018-01-01 08:10:00+00, f@2018-01-01 08:30:00+00]"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            format!("{:?}", inside.when_true().unwrap()),
+            "{[2018-01-01 08:00:00+00, 2018-01-01 08:10:00+00)}"
+        );
+        assert_eq!(inside.duration_true(), TimeDelta::minutes(10));
+        assert_eq!(inside.duration_false(), TimeDelta::minutes(20));
+        assert!(inside.at_false().unwrap().when_true().is_none());
+    }
 }
@@ -3,10 +3,12 @@ use std::{ffi::CStr, hash::Hash, ptr};
 use crate::{
     collections::{
         base::{Collection, Span, SpanSet},
-        datetime::{TsTzSpan, TsTzSpanSet},
+        datetime::{TsTzSet, TsTzSpan, TsTzSpanSet},
     },
     factory,
-    utils::{create_interval, from_interval, from_meos_timestamp, to_meos_timestamp},
+    utils::{
+        create_interval, from_interval, from_meos_array, from_meos_timestamp, to_meos_timestamp,
+    },
     BoundingBox, MeosEnum,
 };
 use chrono::{DateTime, TimeDelta, TimeZone, Utc};
@@ -179,11 +181,9 @@ pub trait Temporal: Collection + Hash {
         let mut count = 0;
         unsafe {
             let instants = meos_sys::temporal_instants(self.inner(), ptr::addr_of_mut!(count));
-
-            std::slice::from_raw_parts(instants, count as usize)
-                .iter()
-                .map(|&instant| <Self::TI as TInstant>::from_inner(instant))
-                .collect()
+            from_meos_array(instants, count, |&instant| {
+                <Self::TI as TInstant>::from_inner(instant)
+            })
         }
     }
 
@@ -242,13 +242,11 @@ pub trait Temporal: Collection + Hash {
     #[doc(alias = "temporal_timestamps")]
     fn timestamps(&self) -> Vec<DateTime<Utc>> {
         let mut count = 0;
-        let timestamps =
-            unsafe { meos_sys::temporal_timestamps(self.inner(), ptr::addr_of_mut!(count)) };
         unsafe {
-            std::slice::from_raw_parts(timestamps, count as usize)
-                .iter()
-                .map(|&timestamp| from_meos_timestamp(timestamp))
-                .collect()
+            let timestamps = meos_sys::temporal_timestamps(self.inner(), ptr::addr_of_mut!(count));
+            from_meos_array(timestamps, count, |&timestamp| {
+                from_meos_timestamp(timestamp)
+            })
         }
     }
 
@@ -259,13 +257,156 @@ pub trait Temporal: Collection + Hash {
     #[doc(alias = "temporal_segments")]
     fn segments(&self) -> Vec<Self::TS> {
         let mut count = 0;
-        let segments =
-            unsafe { meos_sys::temporal_segments(self.inner(), ptr::addr_of_mut!(count)) };
         unsafe {
-            std::slice::from_raw_parts(segments, count as usize)
-                .iter()
-                .map(|&segment| <Self::TS as TSequence>::from_inner(segment))
-                .collect()
+            let segments = meos_sys::temporal_segments(self.inner(), ptr::addr_of_mut!(count));
+            from_meos_array(segments, count, |&segment| {
+                <Self::TS as TSequence>::from_inner(segment)
+            })
+        }
+    }
+
+    /// Returns the time of the segments lasting at least `duration`.
+    ///
+    /// A segment spans two consecutive instants of a sequence, so the time
+    /// between the sequences of a sequence set is not a segment.
+    ///
+    /// ## Arguments
+    /// * `duration` - The minimum duration of the segments.
+    ///
+    /// ## Returns
+    /// The time of the segments, or `None` if no segment lasts at least `duration`.
+    #[doc(alias = "temporal_segm_duration")]
+    fn segments_longer_than(&self, duration: TimeDelta) -> Option<TsTzSpanSet> {
+        segments_by_duration(self, duration, true)
+    }
+
+    /// Returns the time of the segments lasting at most `duration`.
+    ///
+    /// A segment spans two consecutive instants of a sequence, so the time
+    /// between the sequences of a sequence set is not a segment.
+    ///
+    /// ## Arguments
+    /// * `duration` - The maximum duration of the segments.
+    ///
+    /// ## Returns
+    /// The time of the segments, or `None` if no segment lasts at most `duration`.
+    #[doc(alias = "temporal_segm_duration")]
+    fn segments_shorter_than(&self, duration: TimeDelta) -> Option<TsTzSpanSet> {
+        segments_by_duration(self, duration, false)
+    }
+
+    /// Returns statistics of the gaps lasting at least `min_gap`, for instance,
+    /// the periods in which a tracker was silent.
+    ///
+    /// A gap is the time between two consecutive instants, either within a
+    /// sequence or between the end of a sequence and the start of the next
+    /// one, whatever the interpolation.
+    fn gap_statistics(&self, min_gap: TimeDelta) -> GapStatistics {
+        self.timestamps()
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .filter(|&gap| gap >= min_gap)
+            .fold(GapStatistics::default(), |mut statistics, gap| {
+                statistics.count += 1;
+                statistics.total += gap;
+                statistics.max = statistics.max.max(gap);
+                statistics
+            })
+    }
+
+    /// Returns the number of sequences in the temporal object.
+    ///
+    /// ## Returns
+    /// The number of sequences, 0 if the temporal object has discrete interpolation.
+    #[doc(alias = "temporal_num_sequences")]
+    fn num_sequences(&self) -> i32 {
+        if is_continuous(self) {
+            unsafe { meos_sys::temporal_num_sequences(self.inner()) }
+        } else {
+            0
+        }
+    }
+
+    /// Returns the first sequence in the temporal object.
+    ///
+    /// ## Returns
+    /// The first sequence, None if the temporal object has discrete interpolation.
+    #[doc(alias = "temporal_start_sequence")]
+    fn start_sequence(&self) -> Option<Self::TS> {
+        is_continuous(self).then(|| {
+            <Self::TS as TSequence>::from_inner(unsafe {
+                meos_sys::temporal_start_sequence(self.inner())
+            })
+        })
+    }
+
+    /// Returns the last sequence in the temporal object.
+    ///
+    /// ## Returns
+    /// The last sequence, None if the temporal object has discrete interpolation.
+    #[doc(alias = "temporal_end_sequence")]
+    fn end_sequence(&self) -> Option<Self::TS> {
+        is_continuous(self).then(|| {
+            <Self::TS as TSequence>::from_inner(unsafe {
+                meos_sys::temporal_end_sequence(self.inner())
+            })
+        })
+    }
+
+    /// Returns the n-th sequence in the temporal object.
+    ///
+    /// ## Arguments
+    /// * `n` - The index (0-based).
+    ///
+    /// ## Return
+    /// The n-th sequence if exists, None otherwise.
+    #[doc(alias = "temporal_sequence_n")]
+    fn sequence_n(&self, n: i32) -> Option<Self::TS> {
+        if !is_continuous(self) || n < 0 || n >= self.num_sequences() {
+            return None;
+        }
+        let result = unsafe { meos_sys::temporal_sequence_n(self.inner(), n + 1) };
+        if result.is_null() {
+            None
+        } else {
+            Some(<Self::TS as TSequence>::from_inner(result))
+        }
+    }
+
+    /// Returns the list of sequences in the temporal object.
+    ///
+    /// ## Returns
+    /// A list of sequences, empty if the temporal object has discrete interpolation.
+    #[doc(alias = "temporal_sequences")]
+    fn sequences(&self) -> Vec<Self::TS> {
+        if !is_continuous(self) {
+            return Vec::new();
+        }
+        let mut count = 0;
+        unsafe {
+            let sequences = meos_sys::temporal_sequences(self.inner(), ptr::addr_of_mut!(count));
+            from_meos_array(sequences, count, |&sequence| {
+                <Self::TS as TSequence>::from_inner(sequence)
+            })
+        }
+    }
+
+    /// Returns an iterator over the sequences in the temporal object, which
+    /// copies one sequence at a time.
+    ///
+    /// ## Example
+    /// ```
+    /// # use meos::{meos_initialize, TFloatSequenceSet, Temporal};
+    /// # meos_initialize();
+    /// let temporal: TFloatSequenceSet =
+    ///     "{[1@2020-01-01, 2@2020-01-02], [3@2020-01-03]}".parse().unwrap();
+    /// assert_eq!(temporal.iter_sequences().count(), 2);
+    /// ```
+    fn iter_sequences(&self) -> Sequences<'_, Self> {
+        Sequences {
+            temporal: self,
+            next: 0,
+            end: self.num_sequences(),
         }
     }
 
@@ -487,6 +628,24 @@ pub trait Temporal: Collection + Hash {
         })
     }
 
+    /// Deletes elements from `self` at `timestamps`.
+    ///
+    /// ## Arguments
+    /// * `timestamps` - A `TsTzSet` or a slice of timestamps specifying the elements to delete.
+    /// * `connect` - Whether to connect the potential gaps generated by the deletions.
+    ///
+    /// ## Returns
+    /// The remaining temporal object, a copy of `self` if `timestamps` is empty.
+    #[doc(alias = "temporal_delete_tstzset")]
+    fn delete_at_tstz_set<S: TryInto<TsTzSet>>(&self, timestamps: S, connect: bool) -> Self::Enum {
+        let Ok(timestamps) = timestamps.try_into() else {
+            return factory::<Self::Enum>(unsafe { meos_sys::temporal_copy(self.inner()) });
+        };
+        factory::<Self::Enum>(unsafe {
+            meos_sys::temporal_delete_tstzset(self.inner(), timestamps.inner(), connect)
+        })
+    }
+
     /// Deletes elements from `self` at `time_span`.
     ///
     /// ## Arguments
@@ -524,6 +683,73 @@ pub trait Temporal: Collection + Hash {
         })
     }
 
+    /// Returns a new temporal object with values restricted to the times in `timestamps`.
+    ///
+    /// ## Arguments
+    /// * `timestamps` - A `TsTzSet` or a slice of timestamps to restrict the values to.
+    ///
+    /// ## Returns
+    /// The restricted temporal object, or `None` if `timestamps` is empty or
+    /// `self` is not defined at any of them.
+    #[doc(alias = "temporal_at_tstzset")]
+    fn at_tstz_set<S: TryInto<TsTzSet>>(&self, timestamps: S) -> Option<Self::Enum> {
+        let timestamps = timestamps.try_into().ok()?;
+        let result = unsafe { meos_sys::temporal_at_tstzset(self.inner(), timestamps.inner()) };
+        if result.is_null() {
+            None
+        } else {
+            Some(factory::<Self::Enum>(result))
+        }
+    }
+
+    /// Returns a new temporal object with values restricted to the time before `timestamp`.
+    ///
+    /// ## Arguments
+    /// * `timestamp` - The timestamp to restrict the values to.
+    /// * `strict` - Whether to exclude the value at `timestamp`.
+    ///
+    /// ## Returns
+    /// The restricted temporal object, or `None` if `self` starts after `timestamp`.
+    #[doc(alias = "temporal_before_timestamptz")]
+    fn before<Tz: TimeZone>(&self, timestamp: DateTime<Tz>, strict: bool) -> Option<Self::Enum> {
+        let result = unsafe {
+            meos_sys::temporal_before_timestamptz(
+                self.inner(),
+                to_meos_timestamp(&timestamp),
+                strict,
+            )
+        };
+        if result.is_null() {
+            None
+        } else {
+            Some(factory::<Self::Enum>(result))
+        }
+    }
+
+    /// Returns a new temporal object with values restricted to the time after `timestamp`.
+    ///
+    /// ## Arguments
+    /// * `timestamp` - The timestamp to restrict the values to.
+    /// * `strict` - Whether to exclude the value at `timestamp`.
+    ///
+    /// ## Returns
+    /// The restricted temporal object, or `None` if `self` ends before `timestamp`.
+    #[doc(alias = "temporal_after_timestamptz")]
+    fn after<Tz: TimeZone>(&self, timestamp: DateTime<Tz>, strict: bool) -> Option<Self::Enum> {
+        let result = unsafe {
+            meos_sys::temporal_after_timestamptz(
+                self.inner(),
+                to_meos_timestamp(&timestamp),
+                strict,
+            )
+        };
+        if result.is_null() {
+            None
+        } else {
+            Some(factory::<Self::Enum>(result))
+        }
+    }
+
     /// Returns a new temporal object with values restricted to the time `time_span`.
     ///
     /// ## Arguments
@@ -566,12 +792,18 @@ pub trait Temporal: Collection + Hash {
     /// Returns a new temporal object with values at any of the values of `timestamps` removed.
     ///
     /// ## Arguments
-    /// * `timestamps` - A timestamp specifying the values to remove.
+    /// * `timestamps` - The timestamps specifying the values to remove.
+    ///
+    /// ## Returns
+    /// The remaining temporal object, a copy of `self` if `timestamps` is empty.
     #[doc(alias = "temporal_minus_tstzset")]
     fn minus_timestamp_set<Tz: TimeZone>(&self, timestamps: &[DateTime<Tz>]) -> Self::Enum {
-        let timestamps: Vec<_> = timestamps.iter().map(to_meos_timestamp).collect();
-        let set = unsafe { meos_sys::tstzset_make(timestamps.as_ptr(), timestamps.len() as i32) };
-        factory::<Self::Enum>(unsafe { meos_sys::temporal_minus_tstzset(self.inner(), set) })
+        let Ok(set) = TsTzSet::try_from(timestamps) else {
+            return factory::<Self::Enum>(unsafe { meos_sys::temporal_copy(self.inner()) });
+        };
+        factory::<Self::Enum>(unsafe {
+            meos_sys::temporal_minus_tstzset(self.inner(), set.inner())
+        })
     }
 
     /// Returns a new temporal object with values at `time_span` removed.
@@ -789,6 +1021,46 @@ pub trait Temporal: Collection + Hash {
     }
 
     // ------------------------- Split Operations ------------------------------
+    /// Returns up to `span_count` time spans covering the temporal object, each
+    /// spanning a similar number of instants.
+    ///
+    /// ## Arguments
+    /// * `span_count` - Maximum number of spans.
+    #[doc(alias = "temporal_split_n_spans")]
+    fn split_n_spans(&self, span_count: i32) -> Vec<TsTzSpan> {
+        let mut count = 0;
+        unsafe {
+            let spans = meos_sys::temporal_split_n_spans(
+                self.inner(),
+                span_count,
+                ptr::addr_of_mut!(count),
+            );
+            from_meos_array(spans, count, |span| {
+                TsTzSpan::from_inner(meos_sys::span_copy(span))
+            })
+        }
+    }
+
+    /// Returns the time spans covering the temporal object, each spanning
+    /// `elem_count` consecutive instants.
+    ///
+    /// ## Arguments
+    /// * `elem_count` - Number of instants per span.
+    #[doc(alias = "temporal_split_each_n_spans")]
+    fn split_each_n_spans(&self, elem_count: i32) -> Vec<TsTzSpan> {
+        let mut count = 0;
+        unsafe {
+            let spans = meos_sys::temporal_split_each_n_spans(
+                self.inner(),
+                elem_count,
+                ptr::addr_of_mut!(count),
+            );
+            from_meos_array(spans, count, |span| {
+                TsTzSpan::from_inner(meos_sys::span_copy(span))
+            })
+        }
+    }
+
     /// Splits the temporal object into multiple pieces based on the given duration.
     ///
     /// ## Arguments
@@ -1025,6 +1297,87 @@ pub trait Temporal: Collection + Hash {
     fn temporal_not_equal_value(&self, other: &Self::Type) -> Self::TBoolType;
 }
 
+fn is_continuous<T: Temporal>(temporal: &T) -> bool {
+    matches!(
+        temporal.interpolation(),
+        TInterpolation::Stepwise | TInterpolation::Linear
+    )
+}
+
+/// Returns the time of the segments of `temporal` lasting at least, or else at
+/// most, `duration`.
+fn segments_by_duration<T: Temporal>(
+    temporal: &T,
+    duration: TimeDelta,
+    at_least: bool,
+) -> Option<TsTzSpanSet> {
+    if !is_continuous(temporal) {
+        return None;
+    }
+    let interval = create_interval(duration);
+    let result = unsafe {
+        meos_sys::temporal_segm_duration(
+            temporal.inner(),
+            ptr::from_ref(&interval),
+            at_least,
+            false,
+        )
+    };
+    if result.is_null() {
+        return None;
+    }
+    let time = TsTzSpanSet::from_inner(unsafe { meos_sys::temporal_time(result.cast()) });
+    unsafe { libc::free(result.cast()) };
+    Some(time)
+}
+
+/// Statistics of the gaps of a temporal object, created by [`Temporal::gap_statistics`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GapStatistics {
+    /// The number of gaps.
+    pub count: usize,
+    /// The total duration of the gaps.
+    pub total: TimeDelta,
+    /// The duration of the longest gap, zero if there are no gaps.
+    pub max: TimeDelta,
+}
+
+/// Iterator over the sequences of a temporal object, created by [`Temporal::iter_sequences`].
+pub struct Sequences<'a, T: Temporal> {
+    temporal: &'a T,
+    next: i32,
+    end: i32,
+}
+
+impl<T: Temporal> Iterator for Sequences<'_, T> {
+    type Item = T::TS;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.end {
+            return None;
+        }
+        self.next += 1;
+        self.temporal.sequence_n(self.next - 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.end - self.next) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: Temporal> DoubleEndedIterator for Sequences<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.next >= self.end {
+            return None;
+        }
+        self.end -= 1;
+        self.temporal.sequence_n(self.end)
+    }
+}
+
+impl<T: Temporal> ExactSizeIterator for Sequences<'_, T> {}
+
 pub trait OrderedTemporal: Temporal {
     /// Returns the minimum value of the temporal object.
     ///
@@ -1496,6 +1849,21 @@ macro_rules! impl_simple_traits_for_temporal {
                 }
             }
 
+            impl Eq for $type {}
+
+            impl PartialOrd for $type {
+                fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            impl Ord for $type {
+                fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                    let cmp = unsafe { meos_sys::temporal_cmp(self.inner(), other.inner()) };
+                    cmp.cmp(&0)
+                }
+            }
+
             impl Hash for $type {
                 fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
                     let hash = unsafe { meos_sys::temporal_hash(self.inner()) };
@@ -1516,14 +1884,16 @@ macro_rules! impl_simple_traits_for_temporal {
                 }
             }
         }
+
+        $crate::impl_send_sync!($type);
     }
 }
 
 macro_rules! impl_always_and_ever_value_equality_functions {
-    ($type:ident, $transform_function:expr) => {
+    ($type:ident, with_self $transform_function:expr) => {
         paste::paste! {
             fn always_equal_than_value(&self, value: Self::Type) -> Option<bool> {
-                let result = unsafe { meos_sys::[<always_eq_t $type _ $type>](self.inner(), $transform_function(&value)) };
+                let result = unsafe { meos_sys::[<always_eq_t $type _ $type>](self.inner(), ($transform_function)(self, &value)) };
                 if result != -1 {
                     Some(result == 1)
                 } else {
@@ -1531,7 +1901,7 @@ macro_rules! impl_always_and_ever_value_equality_functions {
                 }
             }
             fn always_not_equal_than_value(&self, value: Self::Type) -> Option<bool> {
-                let result = unsafe { meos_sys::[<always_ne_t $type _ $type>](self.inner(), $transform_function(&value)) };
+                let result = unsafe { meos_sys::[<always_ne_t $type _ $type>](self.inner(), ($transform_function)(self, &value)) };
                 if result != -1 {
                     Some(result == 1)
                 } else {
@@ -1539,7 +1909,7 @@ macro_rules! impl_always_and_ever_value_equality_functions {
                 }
             }
             fn ever_equal_than_value(&self, value: Self::Type) -> Option<bool> {
-                let result = unsafe { meos_sys::[<ever_eq_t $type _ $type>](self.inner(), $transform_function(&value)) };
+                let result = unsafe { meos_sys::[<ever_eq_t $type _ $type>](self.inner(), ($transform_function)(self, &value)) };
                 if result != -1 {
                     Some(result == 1)
                 } else {
@@ -1547,7 +1917,7 @@ macro_rules! impl_always_and_ever_value_equality_functions {
                 }
             }
             fn ever_not_equal_than_value(&self, value: Self::Type) -> Option<bool> {
-                let result = unsafe { meos_sys::[<ever_ne_t $type _ $type>](self.inner(), $transform_function(&value)) };
+                let result = unsafe { meos_sys::[<ever_ne_t $type _ $type>](self.inner(), ($transform_function)(self, &value)) };
                 if result != -1 {
                     Some(result == 1)
                 } else {
@@ -1557,6 +1927,12 @@ macro_rules! impl_always_and_ever_value_equality_functions {
             }
         }
     };
+    ($type:ident, $transform_function:expr) => {
+        impl_always_and_ever_value_equality_functions!(
+            $type,
+            with_self |_: &Self, value| ($transform_function)(value)
+        );
+    };
     ($type:ident) => {
         impl_always_and_ever_value_equality_functions!($type, |&x| x);
     };
@@ -1658,8 +2034,270 @@ macro_rules! impl_ordered_temporal_functions {
     };
 }
 
+/// Implements the conversion from a temporal type into another one for the
+/// enum and each of its subtypes, using the `meos` function `$convert`.
+macro_rules! impl_temporal_conversion {
+    (From, $from:ident => $to:ident, $convert:path) => {
+        paste::paste! {
+            impl_temporal_conversion!(@from $from, $to, $convert);
+            impl_temporal_conversion!(@from [<$from Instant>], [<$to Instant>], $convert);
+            impl_temporal_conversion!(@from [<$from Sequence>], [<$to Sequence>], $convert);
+            impl_temporal_conversion!(@from [<$from SequenceSet>], [<$to SequenceSet>], $convert);
+        }
+    };
+    (TryFrom, $from:ident => $to:ident, $convert:path) => {
+        paste::paste! {
+            impl_temporal_conversion!(@try_from $from, $to, $convert);
+            impl_temporal_conversion!(@try_from [<$from Instant>], [<$to Instant>], $convert);
+            impl_temporal_conversion!(@try_from [<$from Sequence>], [<$to Sequence>], $convert);
+            impl_temporal_conversion!(@try_from [<$from SequenceSet>], [<$to SequenceSet>], $convert);
+        }
+    };
+    (@from $from:ty, $to:ty, $convert:path) => {
+        impl From<&$from> for $to {
+            fn from(value: &$from) -> Self {
+                <$to as $crate::Temporal>::from_inner_as_temporal(unsafe {
+                    $convert($crate::Temporal::inner(value))
+                })
+            }
+        }
+
+        impl From<$from> for $to {
+            fn from(value: $from) -> Self {
+                Self::from(&value)
+            }
+        }
+    };
+    (@try_from $from:ty, $to:ty, $convert:path) => {
+        impl TryFrom<&$from> for $to {
+            type Error = $crate::ParseError;
+            fn try_from(value: &$from) -> Result<Self, Self::Error> {
+                let result = $crate::catch_meos_error(|| unsafe {
+                    $convert($crate::Temporal::inner(value))
+                })
+                .map_err(|_| $crate::ParseError)?;
+                if result.is_null() {
+                    Err($crate::ParseError)
+                } else {
+                    Ok(<$to as $crate::Temporal>::from_inner_as_temporal(result))
+                }
+            }
+        }
+
+        impl TryFrom<$from> for $to {
+            type Error = $crate::ParseError;
+            fn try_from(value: $from) -> Result<Self, Self::Error> {
+                Self::try_from(&value)
+            }
+        }
+    };
+}
+
+/// Implements `from_base_temporal`, which creates a temporal object with a
+/// constant value over the time of another temporal object.
+///
+/// Points are created with the SRID of the other temporal object if it is a
+/// spatial one, or with the default SRID otherwise.
+macro_rules! impl_from_base_temporal {
+    ($type:ty, text) => {
+        impl_from_base_temporal!(@fn $type, &str, "ttext_from_base_temp", |value, other| {
+            let text = to_ctext(value);
+            let result =
+                $crate::factory::<Self>(unsafe { meos_sys::ttext_from_base_temp(text, other.inner()) });
+            unsafe { libc::free(text.cast()) };
+            result
+        });
+    };
+    ($type:ty, point, $geodetic:literal) => {
+        impl_from_base_temporal!(@fn $type, &Point, "tpoint_from_base_temp", |value, other| {
+            let srid = $crate::catch_meos_error(|| unsafe { meos_sys::tspatial_srid(other.inner()) })
+                .ok()
+                .filter(|&srid| srid != 0)
+                .unwrap_or(default_srid($geodetic));
+            let point = point_to_gserialized(value, srid, $geodetic);
+            let result =
+                $crate::factory::<Self>(unsafe { meos_sys::tpoint_from_base_temp(point, other.inner()) });
+            unsafe { libc::free(point.cast()) };
+            result
+        });
+    };
+    ($type:ty, $base_type:ty, $meos:literal) => {
+        paste::paste! {
+            impl_from_base_temporal!(@fn $type, $base_type, $meos, |value, other| {
+                $crate::factory::<Self>(unsafe { meos_sys::[<$meos>](value, other.inner()) })
+            });
+        }
+    };
+    (@fn $type:ty, $base_type:ty, $meos:literal, |$value:ident, $other:ident| $body:block) => {
+        impl $type {
+            /// Creates a temporal object with the constant value `value` over the time of `other`.
+            ///
+            /// ## Arguments
+            /// * `value` - Base value.
+            /// * `other` - Temporal object whose time frame is used as the temporal dimension.
+            ///
+            /// ## Returns
+            /// A new temporal object with the same subtype and time as `other`.
+            #[doc(alias = $meos)]
+            pub fn from_base_temporal<T: $crate::Temporal>($value: $base_type, $other: &T) -> Self $body
+        }
+    };
+}
+
+pub(crate) use impl_temporal_conversion;
+
+pub(crate) use impl_from_base_temporal;
+
 pub(crate) use impl_ordered_temporal_functions;
 
 pub(crate) use impl_always_and_ever_value_equality_functions;
 
 pub(crate) use impl_simple_traits_for_temporal;
+
+#[cfg(test)]
+#[serial_test::serial]
+mod tests {
+    use super::*;
+    use crate::{
+        meos_initialize, meos_initialize_timezone, IntSpan, TFloat, TInt, TIntInstant, WKBVariant,
+    };
+
+    #[test]
+    fn gaps() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let value: TFloat = "{[1@2018-01-01 08:00:00+00, 2@2018-01-01 08:01:00+00, \
+                             3@2018-01-01 08:31:00+00], [4@2018-01-01 10:00:00+00]}"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            format!(
+                "{:?}",
+                value.segments_longer_than(TimeDelta::minutes(30)).unwrap()
+            ),
+            "{[2018-01-01 08:01:00+00, 2018-01-01 08:31:00+00]}"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                value
+                    .segments_shorter_than(TimeDelta::minutes(1))
+                    .unwrap()
+                    .start_span()
+            ),
+            "[2018-01-01 08:00:00+00, 2018-01-01 08:01:00+00]"
+        );
+        assert!(value.segments_longer_than(TimeDelta::hours(1)).is_none());
+        let statistics = value.gap_statistics(TimeDelta::minutes(30));
+        assert_eq!(statistics.count, 2);
+        assert_eq!(statistics.total, TimeDelta::minutes(30 + 89));
+        assert_eq!(statistics.max, TimeDelta::minutes(89));
+        assert_eq!(
+            value.gap_statistics(TimeDelta::minutes(90)),
+            GapStatistics::default()
+        );
+    }
+
+    #[test]
+    fn delete_at_tstz_set() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let value: TFloat = "[1@2018-01-01 08:00:00+00, 2@2018-01-01 08:10:00+00, \
+                             3@2018-01-01 08:20:00+00]"
+            .parse()
+            .unwrap();
+        let t = Utc.with_ymd_and_hms(2018, 1, 1, 8, 10, 0).unwrap();
+        assert_eq!(value.delete_at_tstz_set(&[t][..], true).num_instants(), 2);
+        let set = TsTzSet::try_from(&[t][..]).unwrap();
+        assert_eq!(value.delete_at_tstz_set(set, true).num_instants(), 2);
+        let empty: &[DateTime<Utc>] = &[];
+        assert_eq!(value.delete_at_tstz_set(empty, true), value);
+    }
+
+    #[test]
+    fn ordering() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let parse = |string: &str| -> TInt { string.parse().unwrap() };
+        let mut values: Vec<TInt> = [
+            "[3@2018-01-03 08:00:00+00]",
+            "[1@2018-01-01 08:00:00+00]",
+            "[2@2018-01-02 08:00:00+00]",
+        ]
+        .into_iter()
+        .map(parse)
+        .collect();
+        values.sort();
+        let expected: Vec<TInt> = [
+            "[1@2018-01-01 08:00:00+00]",
+            "[2@2018-01-02 08:00:00+00]",
+            "[3@2018-01-03 08:00:00+00]",
+        ]
+        .into_iter()
+        .map(parse)
+        .collect();
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn time_restrictions() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let t = Utc.with_ymd_and_hms(2018, 1, 1, 8, 0, 0).unwrap();
+        let value: TInt = "[1@2018-01-01 08:00:00+00, 2@2018-01-01 08:10:00+00]"
+            .parse()
+            .unwrap();
+        let expected: TInt = "[1@2018-01-01 08:00:00+00, 1@2018-01-01 08:05:00+00)"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            value.before(t + TimeDelta::minutes(5), true).unwrap(),
+            expected
+        );
+        assert!(value.after(t + TimeDelta::hours(1), false).is_none());
+        let times = [t, t + TimeDelta::minutes(10), t + TimeDelta::hours(1)];
+        let expected: TInt = "{1@2018-01-01 08:00:00+00, 2@2018-01-01 08:10:00+00}"
+            .parse()
+            .unwrap();
+        assert_eq!(value.at_tstz_set(&times[..]).unwrap(), expected);
+        let set = TsTzSet::try_from(&times[..]).unwrap();
+        assert_eq!(set.num_values(), 3);
+        assert!(value.at_tstz_set(set).is_some());
+        let empty: &[DateTime<Utc>] = &[];
+        assert!(value.at_tstz_set(empty).is_none());
+        assert_eq!(value.minus_timestamp_set(empty), value);
+        assert!("{2018-01-01".parse::<TsTzSet>().is_err());
+    }
+
+    #[test]
+    fn values_across_threads() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let value: TFloat = "[1@2018-01-01 08:00:00+00, 3@2018-01-01 08:10:00+00]"
+            .parse()
+            .unwrap();
+        let wkb = value.as_wkb(WKBVariant::NDR).to_vec();
+        let shared = value.clone();
+        let (doubled, parsed, instant, decoded) = std::thread::spawn(move || {
+            meos_initialize();
+            let parsed: TInt = "[1@2018-01-01 08:00:00+00]".parse().unwrap();
+            let t = Utc.with_ymd_and_hms(2018, 1, 1, 8, 0, 0).unwrap();
+            let instant = TIntInstant::from_value_and_timestamp(2, t);
+            let span: IntSpan = (1..3).into();
+            assert!(span.contains(&instant.value()));
+            (&shared * 2.0, parsed, instant, TFloat::from_wkb(&wkb))
+        })
+        .join()
+        .unwrap();
+        let expected: TFloat = "[2@2018-01-01 08:00:00+00, 6@2018-01-01 08:10:00+00]"
+            .parse()
+            .unwrap();
+        assert_eq!(doubled, expected);
+        assert_eq!(parsed, "[1@2018-01-01 08:00:00+00]".parse().unwrap());
+        assert_eq!(
+            TInt::Instant(instant),
+            "2@2018-01-01 08:00:00+00".parse().unwrap()
+        );
+        assert_eq!(decoded, value);
+    }
+}
@@ -1,3 +1,9 @@
+use std::{error, fmt};
+
+use chrono::{DateTime, Utc};
+
+use crate::catch_meos_error;
+
 use super::{interpolation::TInterpolation, temporal::Temporal, tinstant::TInstant};
 
 pub trait TSequence: Temporal {
@@ -10,23 +16,20 @@ pub trait TSequence: Temporal {
     ///
     /// ## Note
     /// We assume that the lower bound will be inclusive and
-    /// the upper one exclusive (except for Discrete interpolations and instantaneous sequences, where it's inclusive), if you find yourself needing another variant, report it.
+    /// the upper one exclusive (except for Discrete interpolations and instantaneous sequences, where it's inclusive),
+    /// use a [`TSequenceBuilder`] to choose them explicitly.
     fn new<Inst: AsRef<Self::TI>>(values: &[Inst], interpolation: TInterpolation) -> Self {
+        crate::meos_initialize();
         let mut t_list: Vec<_> = values
             .iter()
             .map(|i| i.as_ref().inner_as_tinstant().cast_mut())
             .collect();
-        // The default for discrete instances or instantaneous sequences is an inclusive upper bound
-        let upper_inclusive = matches!(
-            interpolation,
-            TInterpolation::Discrete | TInterpolation::Stepwise
-        ) || values.len() == 1;
         TSequence::from_inner(unsafe {
             meos_sys::tsequence_make(
                 t_list.as_mut_ptr(),
                 t_list.len() as i32,
                 true,
-                upper_inclusive,
+                default_upper_inclusive(interpolation, values.len()),
                 interpolation as u32,
                 true,
             )
@@ -46,3 +49,213 @@ pub trait TSequence: Temporal {
         unsafe { meos_sys::temporal_upper_inc(self.inner()) }
     }
 }
+
+/// Builder of temporal sequences with explicit bounds and normalization.
+///
+/// ## Example
+/// ```
+/// # use chrono::{TimeZone, Utc};
+/// # use meos::{meos_initialize, TFloatInstant, TFloatSequence, TInstant, TInterpolation, TSequenceBuilder};
+/// # meos_initialize();
+/// let t = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+/// let mut builder = TSequenceBuilder::<TFloatSequence>::new(TInterpolation::Linear)
+///     .lower_inclusive(false)
+///     .upper_inclusive(true);
+/// builder.push(TFloatInstant::from_value_and_timestamp(1.0, t)).unwrap();
+/// builder.push(TFloatInstant::from_value_and_timestamp(2.0, t + chrono::TimeDelta::hours(1))).unwrap();
+/// let sequence = builder.build().unwrap();
+/// ```
+pub struct TSequenceBuilder<S: TSequence> {
+    instants: Vec<S::TI>,
+    interpolation: TInterpolation,
+    lower_inclusive: bool,
+    upper_inclusive: Option<bool>,
+    normalize: bool,
+}
+
+impl<S: TSequence> TSequenceBuilder<S> {
+    /// Creates an empty builder of sequences with the given interpolation, an
+    /// inclusive lower bound, the upper bound of [`TSequence::new`] and normalization.
+    pub fn new(interpolation: TInterpolation) -> Self {
+        Self {
+            instants: Vec::new(),
+            interpolation,
+            lower_inclusive: true,
+            upper_inclusive: None,
+            normalize: true,
+        }
+    }
+
+    /// Sets whether the lower bound is inclusive.
+    pub fn lower_inclusive(mut self, lower_inclusive: bool) -> Self {
+        self.lower_inclusive = lower_inclusive;
+        self
+    }
+
+    /// Sets whether the upper bound is inclusive.
+    pub fn upper_inclusive(mut self, upper_inclusive: bool) -> Self {
+        self.upper_inclusive = Some(upper_inclusive);
+        self
+    }
+
+    /// Sets whether redundant instants are removed when building the sequence.
+    pub fn normalize(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        self
+    }
+
+    /// Appends `instant` to the sequence.
+    ///
+    /// ## Returns
+    /// An error if `instant` is not after the last instant pushed.
+    pub fn push(&mut self, instant: S::TI) -> Result<(), TSequenceBuilderError> {
+        if let Some(last) = self.instants.last() {
+            if instant.timestamp() <= last.timestamp() {
+                return Err(TSequenceBuilderError::OutOfOrder {
+                    last: last.timestamp(),
+                    instant: instant.timestamp(),
+                });
+            }
+        }
+        self.instants.push(instant);
+        Ok(())
+    }
+
+    /// Returns the number of instants pushed.
+    pub fn len(&self) -> usize {
+        self.instants.len()
+    }
+
+    /// Returns whether no instant has been pushed.
+    pub fn is_empty(&self) -> bool {
+        self.instants.is_empty()
+    }
+
+    /// Builds the sequence.
+    ///
+    /// ## Returns
+    /// An error if no instant was pushed or if `meos` rejects the sequence, e.g.
+    /// an instantaneous sequence with an exclusive bound or a linear sequence of
+    /// a type with discrete values.
+    #[doc(alias = "tsequence_make")]
+    pub fn build(self) -> Result<S, TSequenceBuilderError>
+    where
+        S::TI: AsRef<S::TI>,
+    {
+        if self.instants.is_empty() {
+            return Err(TSequenceBuilderError::Empty);
+        }
+        if self.upper_inclusive.is_none() && self.lower_inclusive && self.normalize {
+            return catch_meos_error(|| S::new(&self.instants, self.interpolation))
+                .map_err(TSequenceBuilderError::Invalid);
+        }
+        let upper_inclusive = self
+            .upper_inclusive
+            .unwrap_or_else(|| default_upper_inclusive(self.interpolation, self.instants.len()));
+        let mut t_list: Vec<_> = self
+            .instants
+            .iter()
+            .map(|i| i.inner_as_tinstant().cast_mut())
+            .collect();
+        let sequence = catch_meos_error(|| unsafe {
+            meos_sys::tsequence_make(
+                t_list.as_mut_ptr(),
+                t_list.len() as i32,
+                self.lower_inclusive,
+                upper_inclusive,
+                self.interpolation as u32,
+                self.normalize,
+            )
+        })
+        .map_err(TSequenceBuilderError::Invalid)?;
+        Ok(S::from_inner(sequence))
+    }
+}
+
+/// Returns the default upper bound of a sequence, which is inclusive for
+/// discrete and step interpolations and for instantaneous sequences.
+fn default_upper_inclusive(interpolation: TInterpolation, num_instants: usize) -> bool {
+    matches!(
+        interpolation,
+        TInterpolation::Discrete | TInterpolation::Stepwise
+    ) || num_instants == 1
+}
+
+/// Error returned while building a sequence with a [`TSequenceBuilder`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TSequenceBuilderError {
+    /// An instant was pushed at or before the timestamp of the last instant.
+    OutOfOrder {
+        last: DateTime<Utc>,
+        instant: DateTime<Utc>,
+    },
+    /// No instant was pushed.
+    Empty,
+    /// `meos` rejected the sequence, with the given message.
+    Invalid(String),
+}
+
+impl fmt::Display for TSequenceBuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfOrder { last, instant } => write!(
+                f,
+                "instant at {instant} is not after the last instant at {last}"
+            ),
+            Self::Empty => write!(f, "no instant was pushed"),
+            Self::Invalid(message) => write!(f, "invalid sequence: {message}"),
+        }
+    }
+}
+
+impl error::Error for TSequenceBuilderError {}
+
+#[cfg(test)]
+#[serial_test::serial]
+mod tests {
+    use chrono::{TimeDelta, TimeZone};
+
+    use super::*;
+    use crate::{meos_initialize, meos_initialize_timezone, TFloatInstant, TFloatSequence};
+
+    #[test]
+    fn builder() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let t = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let mut builder = TSequenceBuilder::<TFloatSequence>::new(TInterpolation::Linear)
+            .lower_inclusive(false)
+            .upper_inclusive(true);
+        builder
+            .push(TFloatInstant::from_value_and_timestamp(1.0, t))
+            .unwrap();
+        builder
+            .push(TFloatInstant::from_value_and_timestamp(
+                2.0,
+                t + TimeDelta::hours(1),
+            ))
+            .unwrap();
+        assert_eq!(
+            builder.push(TFloatInstant::from_value_and_timestamp(3.0, t)),
+            Err(TSequenceBuilderError::OutOfOrder {
+                last: t + TimeDelta::hours(1),
+                instant: t,
+            })
+        );
+        assert!(matches!(
+            builder.push(TFloatInstant::from_value_and_timestamp(
+                3.0,
+                t + TimeDelta::hours(1),
+            )),
+            Err(TSequenceBuilderError::OutOfOrder { .. })
+        ));
+        assert_eq!(builder.len(), 2);
+        let sequence = builder.build().unwrap();
+        assert!(!sequence.is_lower_inclusive());
+        assert!(sequence.is_upper_inclusive());
+
+        let builder = TSequenceBuilder::<TFloatSequence>::new(TInterpolation::Linear);
+        assert!(builder.is_empty());
+        assert_eq!(builder.build().err(), Some(TSequenceBuilderError::Empty));
+    }
+}
@@ -1,4 +1,12 @@
-use super::{temporal::Temporal, tsequence::TSequence};
+use std::ptr;
+
+use chrono::TimeDelta;
+
+use crate::utils::create_interval;
+
+use super::{
+    interpolation::TInterpolation, temporal::Temporal, tinstant::TInstant, tsequence::TSequence,
+};
 
 pub trait TSequenceSet: Temporal {
     /// ## Arguments
@@ -8,11 +16,82 @@ pub trait TSequenceSet: Temporal {
     /// ## Returns
     /// Returns an instance of a type implementing the `TSequenceSet` trait.
     fn new(values: &[Self::TS], normalize: bool) -> Self {
+        crate::meos_initialize();
         let mut t_list: Vec<_> = values.iter().map(TSequence::inner_as_tsequence).collect();
         TSequenceSet::from_inner(unsafe {
             meos_sys::tsequenceset_make(t_list.as_mut_ptr().cast::<*mut _>(), t_list.len() as i32, normalize)
         })
     }
 
+    /// Creates a temporal sequence set from instants, starting a new sequence
+    /// wherever the gap between two consecutive instants exceeds the given limits.
+    ///
+    /// ## Arguments
+    /// * `instants` - A slice of temporal instants (`TInstant`), ordered by timestamp.
+    /// * `interpolation` - The interpolation of the sequences, either stepwise or linear.
+    /// * `max_time` - Maximum time between two consecutive instants of a sequence, if any.
+    /// * `max_dist` - Maximum distance between two consecutive instants of a sequence, if any.
+    ///
+    /// ## Returns
+    /// Returns an instance of a type implementing the `TSequenceSet` trait.
+    #[doc(alias = "tsequenceset_make_gaps")]
+    fn from_instants_with_gaps<Inst: AsRef<Self::TI>>(
+        instants: &[Inst],
+        interpolation: TInterpolation,
+        max_time: Option<TimeDelta>,
+        max_dist: Option<f64>,
+    ) -> Self {
+        crate::meos_initialize();
+        let mut t_list: Vec<_> = instants
+            .iter()
+            .map(|i| i.as_ref().inner_as_tinstant().cast_mut())
+            .collect();
+        let interval = max_time.map(create_interval);
+        TSequenceSet::from_inner(unsafe {
+            meos_sys::tsequenceset_make_gaps(
+                t_list.as_mut_ptr(),
+                t_list.len() as i32,
+                interpolation as u32,
+                interval.as_ref().map_or(ptr::null(), ptr::from_ref),
+                max_dist.unwrap_or_default(),
+            )
+        })
+    }
+
     fn from_inner(inner: *mut meos_sys::TSequenceSet) -> Self;
 }
+
+#[cfg(test)]
+#[serial_test::serial]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::{
+        meos_initialize, meos_initialize_timezone, TFloat, TFloatInstant, TFloatSequenceSet,
+    };
+
+    #[test]
+    fn from_instants_with_gaps() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let t = Utc.with_ymd_and_hms(2018, 1, 1, 8, 0, 0).unwrap();
+        let instants: Vec<_> = [(1., 0), (2., 1), (3., 60)]
+            .into_iter()
+            .map(|(value, minutes)| {
+                TFloatInstant::from_value_and_timestamp(value, t + TimeDelta::minutes(minutes))
+            })
+            .collect();
+        let result = TFloatSequenceSet::from_instants_with_gaps(
+            &instants,
+            TInterpolation::Linear,
+            Some(TimeDelta::minutes(10)),
+            None,
+        );
+        let expected: TFloat = "{[1@2018-01-01 08:00:00+00, 2@2018-01-01 08:01:00+00], \
+                                [3@2018-01-01 09:00:00+00]}"
+            .parse()
+            .unwrap();
+        assert_eq!(TFloat::SequenceSet(result), expected);
+    }
+}
@@ -18,13 +18,15 @@ use crate::{
     temporal::{
         tbool::{TBoolInstant, TBoolSequence, TBoolSequenceSet},
         temporal::{
-            impl_always_and_ever_value_equality_functions, impl_ordered_temporal_functions,
-            impl_simple_traits_for_temporal, OrderedTemporal, Temporal,
+            impl_always_and_ever_value_equality_functions, impl_from_base_temporal,
+            impl_ordered_temporal_functions, impl_simple_traits_for_temporal, OrderedTemporal,
+            Temporal,
         },
         tinstant::TInstant,
         tsequence::TSequence,
         tsequence_set::TSequenceSet,
     },
+    try_factory,
     utils::to_meos_timestamp,
     MeosEnum,
 };
@@ -238,6 +240,8 @@ pub enum TText {
 
 impl_from_str!(TText);
 
+impl_from_base_temporal!(TText, text);
+
 impl MeosEnum for TText {
     fn from_instant(inner: *mut meos_sys::TInstant) -> Self {
         Self::Instant(TTextInstant::from_inner(inner))
@@ -251,9 +255,9 @@ impl MeosEnum for TText {
         Self::SequenceSet(TTextSequenceSet::from_inner(inner))
     }
 
-    fn from_mfjson(mfjson: &str) -> Self {
-        let cstr = CString::new(mfjson).unwrap();
-        factory::<Self>(unsafe { meos_sys::ttext_from_mfjson(cstr.as_ptr()) })
+    fn try_from_mfjson(mfjson: &str) -> Result<Self, ParseError> {
+        let cstr = CString::new(mfjson).map_err(|_| ParseError)?;
+        try_factory::<Self>(|| unsafe { meos_sys::ttext_from_mfjson(cstr.as_ptr()) })
     }
 }
 
@@ -279,6 +283,70 @@ pub trait TTextTrait:
     fn uppercase(&self) -> Self {
         Self::from_inner_as_temporal(unsafe { meos_sys::ttext_upper(self.inner()) })
     }
+
+    /// Returns a new temporal text with the first letter of each word in uppercase
+    /// and the rest in lowercase.
+    #[doc(alias = "ttext_initcap")]
+    fn initcap(&self) -> Self {
+        Self::from_inner_as_temporal(unsafe { meos_sys::ttext_initcap(self.inner()) })
+    }
+
+    /// Returns a new temporal text with `string` prepended to the value(s) of `self`.
+    #[doc(alias = "textcat_text_ttext")]
+    fn prepend_str(&self, string: &str) -> Self {
+        Self::from_inner_as_temporal(unsafe {
+            meos_sys::textcat_text_ttext(to_ctext(string), self.inner())
+        })
+    }
+
+    /// Returns the concatenation of the values of `self` and `other` at each
+    /// instant of their common time, or `None` if they don't overlap in time.
+    #[doc(alias = "textcat_ttext_ttext")]
+    fn concatenate<T: TTextTrait>(&self, other: &T) -> Option<TText> {
+        let result = unsafe { meos_sys::textcat_ttext_ttext(self.inner(), other.inner()) };
+        (!result.is_null()).then(|| factory::<TText>(result))
+    }
+}
+
+fn aggregate<'a, T: TTextTrait + 'a>(
+    values: impl IntoIterator<Item = &'a T>,
+    transfn: unsafe extern "C" fn(
+        *mut meos_sys::SkipList,
+        *const meos_sys::Temporal,
+    ) -> *mut meos_sys::SkipList,
+) -> Option<TText> {
+    let state = values
+        .into_iter()
+        .fold(ptr::null_mut(), |state, value| unsafe {
+            transfn(state, value.inner())
+        });
+    if state.is_null() {
+        return None;
+    }
+    let result = unsafe { meos_sys::temporal_tagg_finalfn(state) };
+    (!result.is_null()).then(|| factory::<TText>(result))
+}
+
+impl TText {
+    /// Returns the temporal minimum of `values`: at each instant, the smallest
+    /// value among those of `values` defined at that instant.
+    ///
+    /// ## Returns
+    /// `None` if `values` is empty.
+    #[doc(alias = "ttext_tmin_transfn")]
+    pub fn tmin<'a, T: TTextTrait + 'a>(values: impl IntoIterator<Item = &'a T>) -> Option<TText> {
+        aggregate(values, meos_sys::ttext_tmin_transfn)
+    }
+
+    /// Returns the temporal maximum of `values`: at each instant, the largest
+    /// value among those of `values` defined at that instant.
+    ///
+    /// ## Returns
+    /// `None` if `values` is empty.
+    #[doc(alias = "ttext_tmax_transfn")]
+    pub fn tmax<'a, T: TTextTrait + 'a>(values: impl IntoIterator<Item = &'a T>) -> Option<TText> {
+        aggregate(values, meos_sys::ttext_tmax_transfn)
+    }
 }
 
 pub struct TTextInstant {
@@ -297,6 +365,7 @@ impl TInstant for TTextInstant {
     }
 
     fn from_value_and_timestamp<Tz: TimeZone>(value: Self::Type, timestamp: DateTime<Tz>) -> Self {
+        crate::meos_initialize();
         Self::from_inner(unsafe {
             meos_sys::ttextinst_make(to_ctext(&value), to_meos_timestamp(&timestamp))
         })
@@ -480,6 +549,8 @@ impl Collection for TText {
 
 impl_simple_traits_for_temporal!(TText);
 
+impl TTextTrait for TText {}
+
 impl OrderedTemporal for TText {
     fn min_value(&self) -> Self::Type {
         from_ctext(unsafe { meos_sys::ttext_min_value(self.inner()) })
@@ -644,4 +715,24 @@ mod tests {
             format!("SequenceSet({})", string.to_owned())
         );
     }
+
+    #[test]
+    fn text_operations() {
+        meos_initialize();
+        meos_initialize_timezone("UTC");
+        let status: TText =
+            "[\"under way\"@2018-01-01 08:00:00+00, \"MOORED\"@2018-01-01 09:00:00+00]"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            format!("{:?}", status.initcap().prepend_str("Status: ")),
+            "Sequence([\"Status: Under Way\"@2018-01-01 08:00:00+00, \"Status: Moored\"@2018-01-01 09:00:00+00])"
+        );
+        let other: TText = "[\"b\"@2018-01-01 08:00:00+00, \"b\"@2018-01-01 09:00:00+00]"
+            .parse()
+            .unwrap();
+        let min = TText::tmin([&status, &other]).unwrap();
+        assert_eq!(min.max_value(), "b");
+        assert!(TText::tmin::<TText>([]).is_none());
+    }
 }
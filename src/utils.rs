@@ -30,3 +30,23 @@ pub(crate) fn from_meos_timestamp(timestamp: meos_sys::TimestampTz) -> DateTime<
     DateTime::from_timestamp_micros(timestamp + MICROSECONDS_UNTIL_2000)
         .expect("Failed to parse DateTime")
 }
+
+/// Converts the `count` values of an array allocated by `meos` with `f`, and frees the array.
+///
+/// ## Safety
+/// `array` must be null or point to `count` initialized values allocated by `meos`.
+pub(crate) unsafe fn from_meos_array<T, R>(
+    array: *mut T,
+    count: i32,
+    f: impl FnMut(&T) -> R,
+) -> Vec<R> {
+    if array.is_null() {
+        return Vec::new();
+    }
+    let result = std::slice::from_raw_parts(array, count as usize)
+        .iter()
+        .map(f)
+        .collect();
+    libc::free(array.cast());
+    result
+}